@@ -0,0 +1,370 @@
+//! Structured dumps of ASTs, as JSON, S-expressions, Graphviz DOT or an
+//! indented tree.
+//!
+//! JSON is serialized from the AST types directly. The other formats render
+//! a generic [`Node`] tree, so that other front-ends can reuse them by
+//! converting their own ASTs into [`Node`]s.
+
+use std::{fmt::Write, str::FromStr};
+
+use serde::Serialize;
+
+use crate::{printer::op_str, Expr, Program, Span, Spanned, Stmt, Type, VarDecl};
+
+/// A format of AST dumps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Sexp,
+    Dot,
+    Tree,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    /// Parses a format from its name, e.g. `json`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "sexp" => Ok(Format::Sexp),
+            "dot" => Ok(Format::Dot),
+            "tree" => Ok(Format::Tree),
+            _ => Err(format!("unknown AST format `{s}`")),
+        }
+    }
+}
+
+/// A generic view of an AST node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    /// An atom naming the node, e.g. `if`, `+` or an identifier.
+    pub label: String,
+    /// The span of source, formatted by the front-end.
+    pub span: Option<String>,
+    /// Whether this is an expression, drawn as an ellipse in DOT.
+    pub expr: bool,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn new(label: impl Into<String>, children: Vec<Node>) -> Self {
+        Self {
+            label: label.into(),
+            span: None,
+            expr: false,
+            children,
+        }
+    }
+
+    pub fn leaf(label: impl Into<String>) -> Self {
+        Self::new(label, Vec::new())
+    }
+
+    pub fn with_span(mut self, span: impl ToString) -> Self {
+        self.span = Some(span.to_string());
+        self
+    }
+
+    /// Marks this node as an expression.
+    pub fn expr(mut self) -> Self {
+        self.expr = true;
+        self
+    }
+
+    /// Renders this node as an S-expression, breaking lines where a list
+    /// would not fit in 80 columns. Labels that are not plain symbols are
+    /// written as quoted strings.
+    pub fn to_sexp(&self) -> String {
+        let mut buf = String::new();
+        self.write_sexp(&mut buf, 0);
+        buf.push('\n');
+        buf
+    }
+
+    fn flat_sexp(&self) -> String {
+        if self.children.is_empty() {
+            return sexp_atom(&self.label);
+        }
+        let mut buf = format!("({}", sexp_atom(&self.label));
+        for child in &self.children {
+            buf.push(' ');
+            buf.push_str(&child.flat_sexp());
+        }
+        buf.push(')');
+        buf
+    }
+
+    fn write_sexp(&self, buf: &mut String, indent: usize) {
+        let flat = self.flat_sexp();
+        if self.children.is_empty() || indent + flat.len() <= 80 {
+            buf.push_str(&flat);
+            return;
+        }
+        buf.push('(');
+        buf.push_str(&sexp_atom(&self.label));
+        for child in &self.children {
+            buf.push('\n');
+            buf.push_str(&" ".repeat(indent + 2));
+            child.write_sexp(buf, indent + 2);
+        }
+        buf.push(')');
+    }
+
+    /// Renders this node as an indented tree, one node per line with its
+    /// span in brackets.
+    pub fn to_tree(&self) -> String {
+        let mut buf = String::new();
+        self.write_tree(&mut buf, "", "");
+        buf
+    }
+
+    fn write_tree(&self, buf: &mut String, first: &str, rest: &str) {
+        buf.push_str(first);
+        buf.push_str(&self.label);
+        if let Some(span) = &self.span {
+            write!(buf, " [{span}]").unwrap();
+        }
+        buf.push('\n');
+        for (i, child) in self.children.iter().enumerate() {
+            if i + 1 == self.children.len() {
+                child.write_tree(buf, &format!("{rest}└── "), &format!("{rest}    "));
+            } else {
+                child.write_tree(buf, &format!("{rest}├── "), &format!("{rest}│   "));
+            }
+        }
+    }
+
+    /// Renders this node as a Graphviz DOT digraph, with statements in boxes
+    /// and expressions in ellipses.
+    pub fn to_dot(&self) -> String {
+        let mut buf = String::from("digraph ast {\n    node [shape=box];\n");
+        self.write_dot(&mut buf, &mut 0);
+        buf.push_str("}\n");
+        buf
+    }
+
+    /// Writes this node and its edges, returning its id.
+    fn write_dot(&self, buf: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+        let label = self.label.replace('\\', "\\\\").replace('"', "\\\"");
+        write!(buf, "    n{id} [label=\"{label}\"").unwrap();
+        if self.expr {
+            buf.push_str(", shape=ellipse");
+        }
+        buf.push_str("];\n");
+        for child in &self.children {
+            let child_id = child.write_dot(buf, next_id);
+            writeln!(buf, "    n{id} -> n{child_id};").unwrap();
+        }
+        id
+    }
+}
+
+/// Returns `label` as an S-expression atom, quoted and escaped unless it is
+/// a plain symbol such as `if`, `:=` or `x1`.
+fn sexp_atom(label: &str) -> String {
+    let plain = !label.is_empty()
+        && !label
+            .chars()
+            .any(|x| x.is_whitespace() || matches!(x, '(' | ')' | '"' | '\\' | ';'));
+    if plain {
+        return label.to_string();
+    }
+    let mut buf = String::from('"');
+    for c in label.chars() {
+        match c {
+            '"' | '\\' => {
+                buf.push('\\');
+                buf.push(c);
+            }
+            '\n' => buf.push_str("\\n"),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
+
+/// Dumps `ast` in `format`, converting it with `to_node` unless the format
+/// is [`Format::Json`].
+pub fn dump<T: Serialize + ?Sized>(
+    ast: &T,
+    to_node: impl FnOnce(&T) -> Node,
+    format: Format,
+) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(ast).unwrap() + "\n",
+        Format::Sexp => to_node(ast).to_sexp(),
+        Format::Dot => to_node(ast).to_dot(),
+        Format::Tree => to_node(ast).to_tree(),
+    }
+}
+
+/// Formats a span as `line:column-line:column`.
+fn span_str(span: Span) -> String {
+    format!("{}-{}", span.start, span.end)
+}
+
+fn type_label(ty: Type) -> &'static str {
+    match ty {
+        Type::Int => "int",
+        Type::Bool => "bool",
+    }
+}
+
+impl From<&Spanned<Expr>> for Node {
+    fn from(value: &Spanned<Expr>) -> Self {
+        let node = match &value.node {
+            Expr::Id(id) => Node::leaf(id),
+            Expr::Num(num) => Node::leaf(num.to_string()),
+            Expr::Op(op, lhs, rhs) => {
+                Node::new(op_str(*op), vec![(&**lhs).into(), (&**rhs).into()])
+            }
+            Expr::Not(e) => Node::new("not", vec![(&**e).into()]),
+        };
+        node.expr().with_span(span_str(value.span))
+    }
+}
+
+impl From<&Spanned<Stmt>> for Node {
+    fn from(value: &Spanned<Stmt>) -> Self {
+        let node = match &value.node {
+            Stmt::If(e, s) => Node::new("if", vec![(&**e).into(), (&**s).into()]),
+            Stmt::IfElse(e, s, o) => {
+                Node::new("if", vec![(&**e).into(), (&**s).into(), (&**o).into()])
+            }
+            Stmt::While(e, s) => Node::new("while", vec![(&**e).into(), (&**s).into()]),
+            Stmt::Assign(i, e) => Node::new(
+                ":=",
+                vec![
+                    Node::leaf(&i.node).with_span(span_str(i.span)),
+                    (&**e).into(),
+                ],
+            ),
+            Stmt::VarDecl(names, ty) => var_node(names, *ty),
+            Stmt::Block(stmts) => block_node(stmts),
+            Stmt::Expr(e) => return (&**e).into(),
+            Stmt::Error => Node::leaf("error"),
+        };
+        node.with_span(span_str(value.span))
+    }
+}
+
+fn var_node(names: &[String], ty: Type) -> Node {
+    let mut children: Vec<_> = names.iter().map(Node::leaf).collect();
+    children.push(Node::leaf(type_label(ty)));
+    Node::new("var", children)
+}
+
+fn block_node(stmts: &[Spanned<Stmt>]) -> Node {
+    Node::new("block", stmts.iter().map(Node::from).collect())
+}
+
+impl From<&Spanned<VarDecl>> for Node {
+    fn from(value: &Spanned<VarDecl>) -> Self {
+        var_node(&value.node.names, value.node.ty).with_span(span_str(value.span))
+    }
+}
+
+impl From<&Program> for Node {
+    fn from(value: &Program) -> Self {
+        let mut children = vec![Node::leaf(&value.name.node).with_span(span_str(value.name.span))];
+        children.extend(value.decls.iter().map(Node::from));
+        children.push(block_node(&value.body.node).with_span(span_str(value.body.span)));
+        Node::new("program", children)
+    }
+}
+
+/// Dumps a complete program in `format`.
+pub fn dump_program(program: &Program, format: Format) -> String {
+    dump(program, |x| x.into(), format)
+}
+
+/// Dumps a list of statements in `format`, as a `block` node.
+pub fn dump_stmt_list(stmts: &[Spanned<Stmt>], format: Format) -> String {
+    dump(stmts, block_node, format)
+}
+
+#[cfg(test)]
+mod test {
+    use lexer::Position;
+
+    use super::*;
+
+    fn sp<T>(node: T, line: u32) -> Spanned<T> {
+        Spanned::new(node, Position::new(line, 0), Position::new(line, 1))
+    }
+
+    #[test]
+    fn generic_nodes() {
+        let node = Node::new(
+            "call",
+            vec![Node::leaf("f").expr(), Node::leaf("say \"hi\"").expr()],
+        );
+        assert_eq!(node.to_sexp(), "(call f \"say \\\"hi\\\"\")\n");
+        assert_eq!(Node::leaf("").to_sexp(), "\"\"\n");
+        assert_eq!(node.to_tree(), "call\n├── f\n└── say \"hi\"\n");
+        assert_eq!(
+            node.to_dot(),
+            "digraph ast {
+    node [shape=box];
+    n0 [label=\"call\"];
+    n1 [label=\"f\", shape=ellipse];
+    n0 -> n1;
+    n2 [label=\"say \\\"hi\\\"\", shape=ellipse];
+    n0 -> n2;
+}
+"
+        );
+
+        let long = Node::new("list", vec![Node::leaf("x".repeat(40)); 2]);
+        assert_eq!(
+            long.to_sexp(),
+            format!("(list\n  {}\n  {})\n", "x".repeat(40), "x".repeat(40))
+        );
+    }
+
+    #[test]
+    fn program() {
+        let program = Program {
+            name: sp("p".into(), 1),
+            decls: vec![sp(
+                VarDecl {
+                    names: vec!["a".into(), "b".into()],
+                    ty: Type::Bool,
+                },
+                2,
+            )],
+            body: sp(
+                vec![
+                    sp(Stmt::Expr(Box::new(sp(Expr::Id("a".into()), 4))), 4),
+                    sp(Stmt::Error, 5),
+                ],
+                3,
+            ),
+        };
+        assert_eq!(
+            dump_program(&program, Format::Sexp),
+            "(program p (var a b bool) (block a error))\n"
+        );
+        assert_eq!(
+            dump_program(&program, Format::Tree),
+            "program
+├── p [1:0-1:1]
+├── var [2:0-2:1]
+│   ├── a
+│   ├── b
+│   └── bool
+└── block [3:0-3:1]
+    ├── a [4:0-4:1]
+    └── error [5:0-5:1]
+"
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&dump_program(&program, Format::Json)).unwrap();
+        assert_eq!(json["decls"][0]["node"]["ty"], "Bool");
+        assert_eq!(json["body"]["node"][1]["node"], "Error");
+    }
+}
@@ -0,0 +1,132 @@
+pub mod dump;
+pub mod printer;
+pub mod visit;
+
+use std::str::FromStr;
+
+pub use lexer::Position;
+use serde::Serialize;
+
+/// The concrete syntax a tree is parsed from or printed as.
+///
+/// Both dialects share this AST. Only the Pascal-like one has a [`Program`]
+/// header; the C-like one terminates every statement with `;` and always
+/// braces the bodies of `if` and `while`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dialect {
+    /// `begin ... end` blocks and `var a: integer` declarations.
+    #[default]
+    Pascal,
+    /// `{ ... }` blocks and `int a` declarations.
+    C,
+}
+
+impl FromStr for Dialect {
+    type Err = String;
+
+    /// Parses a dialect from its name, `pascal` or `c`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pascal" => Ok(Dialect::Pascal),
+            "c" => Ok(Dialect::C),
+            _ => Err(format!("unknown dialect `{s}`")),
+        }
+    }
+}
+
+/// A range of source positions, from `start` inclusive to `end` exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct Span {
+    pub start: Position,
+    pub end: Position,
+}
+
+impl Span {
+    pub fn new(start: Position, end: Position) -> Self {
+        Self { start, end }
+    }
+}
+
+/// A node together with the span of source it was parsed from.
+///
+/// Spans are ignored when comparing, so trees parsed from differently laid
+/// out sources compare equal.
+#[derive(Debug, Clone, Serialize)]
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span,
+}
+
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.node == other.node
+    }
+}
+
+impl<T> Spanned<T> {
+    pub fn new(node: T, start: Position, end: Position) -> Self {
+        Self {
+            node,
+            span: Span::new(start, end),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Type {
+    /// `integer` in the Pascal-like dialect, `int` in the C-like one.
+    Int,
+    /// `boolean` in the Pascal-like dialect, `bool` in the C-like one.
+    Bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Opcode {
+    GreaterThan,
+    Equal,
+    LessThan,
+    Assign,
+    Add,
+    Sub,
+    Mul,
+    And,
+    Or,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Expr {
+    Id(String),
+    Num(u32),
+    Op(Opcode, Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    Not(Box<Spanned<Expr>>),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum Stmt {
+    If(Box<Spanned<Expr>>, Box<Spanned<Stmt>>),
+    IfElse(Box<Spanned<Expr>>, Box<Spanned<Stmt>>, Box<Spanned<Stmt>>),
+    While(Box<Spanned<Expr>>, Box<Spanned<Stmt>>),
+    Assign(Spanned<String>, Box<Spanned<Expr>>),
+    VarDecl(Vec<String>, Type),
+    Block(Vec<Spanned<Stmt>>),
+    Expr(Box<Spanned<Expr>>),
+    /// A placeholder for a statement that failed to parse and was skipped
+    /// during error recovery.
+    Error,
+}
+
+/// A declaration of variables in the declaration section of a [`Program`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VarDecl {
+    pub names: Vec<String>,
+    pub ty: Type,
+}
+
+/// A complete program, i.e. `program name; var ...; begin ... end.`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Program {
+    pub name: Spanned<String>,
+    pub decls: Vec<Spanned<VarDecl>>,
+    /// The statements of the compound body, spanning `begin ... end`.
+    pub body: Spanned<Vec<Spanned<Stmt>>>,
+}
@@ -0,0 +1,412 @@
+//! Printing ASTs back to canonical source text in either [`Dialect`].
+//!
+//! Statements are laid out one per line and indented by four spaces per
+//! nesting level, and expressions get only the parentheses required by
+//! operator precedence, which is the same in both dialects. Comments are not
+//! part of the AST and are lost.
+
+use crate::{Dialect, Expr, Opcode, Program, Spanned, Stmt, Type};
+
+const INDENT: &str = "    ";
+
+/// Prints a complete program, ending with a newline.
+///
+/// In the C-like dialect, which has no program header, the declarations are
+/// printed as statements followed by the statements of the body.
+pub fn print_program(program: &Program, dialect: Dialect) -> String {
+    let mut printer = Printer::new(dialect);
+    printer.program(program);
+    printer.buf
+}
+
+/// Prints a list of statements, ending with a newline. The statements are
+/// separated by `;` in the Pascal-like dialect and terminated by `;` in the
+/// C-like one.
+pub fn print_stmt_list(stmts: &[Spanned<Stmt>], dialect: Dialect) -> String {
+    let mut printer = Printer::new(dialect);
+    printer.stmts(stmts);
+    if dialect == Dialect::Pascal {
+        printer.buf.push('\n');
+    }
+    printer.buf
+}
+
+/// Prints a single statement, without a trailing `;`. [`Stmt::Error`] is
+/// printed as `<error>`, which does not parse.
+pub fn print_stmt(stmt: &Stmt, dialect: Dialect) -> String {
+    let mut printer = Printer::new(dialect);
+    printer.stmt(stmt);
+    printer.buf
+}
+
+pub fn print_expr(expr: &Expr) -> String {
+    let mut printer = Printer::new(Dialect::default());
+    printer.expr(expr, 0);
+    printer.buf
+}
+
+/// Returns the precedence level of `op`, from `and`/`or` at 0 to `*` at 3.
+fn op_level(op: Opcode) -> u8 {
+    match op {
+        Opcode::And | Opcode::Or => 0,
+        Opcode::Equal | Opcode::LessThan | Opcode::GreaterThan | Opcode::Assign => 1,
+        Opcode::Add | Opcode::Sub => 2,
+        Opcode::Mul => 3,
+    }
+}
+
+/// Returns the precedence level of `expr`. Operands bind tightest, at 4.
+fn level(expr: &Expr) -> u8 {
+    match expr {
+        Expr::Id(_) | Expr::Num(_) => 4,
+        Expr::Op(op, _, _) => op_level(*op),
+        Expr::Not(_) => 0,
+    }
+}
+
+pub(crate) fn op_str(op: Opcode) -> &'static str {
+    match op {
+        Opcode::GreaterThan => ">",
+        Opcode::Equal => "=",
+        Opcode::LessThan => "<",
+        Opcode::Assign => ":=",
+        Opcode::Add => "+",
+        Opcode::Sub => "-",
+        Opcode::Mul => "*",
+        Opcode::And => "and",
+        Opcode::Or => "or",
+    }
+}
+
+/// Returns whether `stmt` ends with an `if` without `else`, which would take
+/// the `else` of an enclosing `if` in the Pascal-like dialect.
+fn is_open(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::If(_, _) => true,
+        Stmt::IfElse(_, _, s) | Stmt::While(_, s) => is_open(&s.node),
+        _ => false,
+    }
+}
+
+#[derive(Debug)]
+struct Printer {
+    dialect: Dialect,
+    buf: String,
+    indent: usize,
+}
+
+impl Printer {
+    fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            buf: String::new(),
+            indent: 0,
+        }
+    }
+
+    fn newline(&mut self) {
+        self.buf.push('\n');
+        for _ in 0..self.indent {
+            self.buf.push_str(INDENT);
+        }
+    }
+
+    fn program(&mut self, program: &Program) {
+        if self.dialect == Dialect::C {
+            for decl in &program.decls {
+                self.names(&decl.node.names, decl.node.ty);
+                self.buf.push_str(";\n");
+            }
+            self.stmts(&program.body.node);
+            return;
+        }
+        self.buf.push_str("program ");
+        self.buf.push_str(&program.name.node);
+        self.buf.push_str(";\n");
+        if !program.decls.is_empty() {
+            self.buf.push_str("var");
+            self.indent += 1;
+            for decl in &program.decls {
+                self.newline();
+                self.names(&decl.node.names, decl.node.ty);
+                self.buf.push(';');
+            }
+            self.indent -= 1;
+            self.buf.push('\n');
+        }
+        self.block(&program.body.node);
+        self.buf.push_str(".\n");
+    }
+
+    fn names(&mut self, names: &[String], ty: Type) {
+        match self.dialect {
+            Dialect::Pascal => {
+                self.buf.push_str(&names.join(", "));
+                self.buf.push_str(match ty {
+                    Type::Int => ": integer",
+                    Type::Bool => ": boolean",
+                });
+            }
+            Dialect::C => {
+                self.buf.push_str(match ty {
+                    Type::Int => "int ",
+                    Type::Bool => "bool ",
+                });
+                self.buf.push_str(&names.join(", "));
+            }
+        }
+    }
+
+    fn stmts(&mut self, stmts: &[Spanned<Stmt>]) {
+        for (i, stmt) in stmts.iter().enumerate() {
+            match self.dialect {
+                Dialect::Pascal if i > 0 => {
+                    self.buf.push(';');
+                    self.newline();
+                }
+                Dialect::Pascal => {}
+                Dialect::C => {
+                    for _ in 0..self.indent {
+                        self.buf.push_str(INDENT);
+                    }
+                }
+            }
+            self.stmt(&stmt.node);
+            if self.dialect == Dialect::C {
+                self.buf.push_str(";\n");
+            }
+        }
+    }
+
+    fn block(&mut self, stmts: &[Spanned<Stmt>]) {
+        if self.dialect == Dialect::C {
+            self.buf.push_str("{\n");
+            self.indent += 1;
+            self.stmts(stmts);
+            self.indent -= 1;
+            for _ in 0..self.indent {
+                self.buf.push_str(INDENT);
+            }
+            self.buf.push('}');
+            return;
+        }
+        self.buf.push_str("begin");
+        self.indent += 1;
+        if !stmts.is_empty() {
+            self.newline();
+            self.stmts(stmts);
+        }
+        self.indent -= 1;
+        self.newline();
+        self.buf.push_str("end");
+    }
+
+    /// Prints the statement nested in an `if` or `while`, which starts on
+    /// the same line if it is a block. Returns whether it was printed as a
+    /// block.
+    fn nested(&mut self, stmt: &Spanned<Stmt>) -> bool {
+        match &stmt.node {
+            Stmt::Block(stmts) => {
+                self.buf.push(' ');
+                self.block(stmts);
+                true
+            }
+            // the C-like dialect always braces the body
+            _ if self.dialect == Dialect::C => {
+                self.buf.push(' ');
+                self.block(std::slice::from_ref(stmt));
+                true
+            }
+            node => {
+                self.indent += 1;
+                self.newline();
+                self.stmt(node);
+                self.indent -= 1;
+                false
+            }
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::If(e, s) => {
+                self.buf.push_str("if ");
+                self.expr(&e.node, 0);
+                self.buf.push_str(" then");
+                self.nested(s);
+            }
+            Stmt::IfElse(e, s, o) => {
+                self.buf.push_str("if ");
+                self.expr(&e.node, 0);
+                self.buf.push_str(" then");
+                let in_block = if self.dialect == Dialect::Pascal && is_open(&s.node) {
+                    self.buf.push(' ');
+                    self.block(std::slice::from_ref(&**s));
+                    true
+                } else {
+                    self.nested(s)
+                };
+                if in_block {
+                    self.buf.push(' ');
+                } else {
+                    self.newline();
+                }
+                self.buf.push_str("else");
+                match o.node {
+                    Stmt::If(..) | Stmt::IfElse(..) if self.dialect == Dialect::Pascal => {
+                        self.buf.push(' ');
+                        self.stmt(&o.node);
+                    }
+                    _ => {
+                        self.nested(o);
+                    }
+                }
+            }
+            Stmt::While(e, s) => {
+                self.buf.push_str("while ");
+                self.expr(&e.node, 0);
+                self.buf.push_str(" do");
+                self.nested(s);
+            }
+            Stmt::Assign(i, e) => {
+                self.buf.push_str(&i.node);
+                self.buf.push_str(" := ");
+                self.expr(&e.node, 0);
+            }
+            Stmt::VarDecl(names, ty) => {
+                if self.dialect == Dialect::Pascal {
+                    self.buf.push_str("var ");
+                }
+                self.names(names, *ty);
+            }
+            Stmt::Block(stmts) => self.block(stmts),
+            Stmt::Expr(e) => self.expr(&e.node, 0),
+            Stmt::Error => self.buf.push_str("<error>"),
+        }
+    }
+
+    /// Prints `expr`, parenthesized if it binds looser than `min_level`.
+    fn expr(&mut self, expr: &Expr, min_level: u8) {
+        let parens = level(expr) < min_level;
+        if parens {
+            self.buf.push('(');
+        }
+        match expr {
+            Expr::Id(id) => self.buf.push_str(id),
+            Expr::Num(num) => self.buf.push_str(&num.to_string()),
+            Expr::Op(op, lhs, rhs) => {
+                let level = op_level(*op);
+                // relational operators do not associate
+                let lhs_level = if level == 1 { 2 } else { level };
+                self.expr(&lhs.node, lhs_level);
+                self.buf.push(' ');
+                self.buf.push_str(op_str(*op));
+                self.buf.push(' ');
+                self.expr(&rhs.node, level + 1);
+            }
+            Expr::Not(e) => {
+                self.buf.push_str("not ");
+                self.expr(&e.node, 4);
+            }
+        }
+        if parens {
+            self.buf.push(')');
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Span, VarDecl};
+
+    fn sp<T>(node: T) -> Spanned<T> {
+        Spanned {
+            node,
+            span: Span::default(),
+        }
+    }
+
+    fn id(name: &str) -> Box<Spanned<Expr>> {
+        Box::new(sp(Expr::Id(name.into())))
+    }
+
+    fn op(op: Opcode, lhs: Box<Spanned<Expr>>, rhs: Box<Spanned<Expr>>) -> Box<Spanned<Expr>> {
+        Box::new(sp(Expr::Op(op, lhs, rhs)))
+    }
+
+    fn assign(var: &str, e: Box<Spanned<Expr>>) -> Box<Spanned<Stmt>> {
+        Box::new(sp(Stmt::Assign(sp(var.into()), e)))
+    }
+
+    #[test]
+    fn parentheses() {
+        let e = op(Opcode::Mul, op(Opcode::Add, id("a"), id("b")), id("c"));
+        assert_eq!(print_expr(&e.node), "(a + b) * c");
+        let e = op(Opcode::Sub, id("a"), op(Opcode::Sub, id("b"), id("c")));
+        assert_eq!(print_expr(&e.node), "a - (b - c)");
+        let e = op(
+            Opcode::Equal,
+            op(Opcode::LessThan, id("a"), id("b")),
+            id("c"),
+        );
+        assert_eq!(print_expr(&e.node), "(a < b) = c");
+        let e = Expr::Not(op(Opcode::And, id("a"), Box::new(sp(Expr::Num(1)))));
+        assert_eq!(print_expr(&e), "not (a and 1)");
+    }
+
+    #[test]
+    fn types() {
+        let int = Stmt::VarDecl(vec!["a".into(), "b".into()], Type::Int);
+        let bool = Stmt::VarDecl(vec!["c".into()], Type::Bool);
+        assert_eq!(print_stmt(&int, Dialect::Pascal), "var a, b: integer");
+        assert_eq!(print_stmt(&bool, Dialect::Pascal), "var c: boolean");
+        assert_eq!(print_stmt(&int, Dialect::C), "int a, b");
+        assert_eq!(print_stmt(&bool, Dialect::C), "bool c");
+    }
+
+    #[test]
+    fn dangling_else() {
+        let inner = Box::new(sp(Stmt::If(id("b"), assign("x", id("y")))));
+        let stmt = Stmt::IfElse(id("a"), inner, assign("x", id("z")));
+        assert_eq!(
+            print_stmt(&stmt, Dialect::Pascal),
+            "if a then begin\n    if b then\n        x := y\nend else\n    x := z"
+        );
+        assert_eq!(
+            print_stmt(&stmt, Dialect::C),
+            "if a then {\n    if b then {\n        x := y;\n    };\n} else {\n    x := z;\n}"
+        );
+    }
+
+    #[test]
+    fn program() {
+        let program = Program {
+            name: sp("p".into()),
+            decls: vec![sp(VarDecl {
+                names: vec!["a".into()],
+                ty: Type::Bool,
+            })],
+            body: sp(vec![*assign("a", id("a")), sp(Stmt::Error)]),
+        };
+        assert_eq!(
+            print_program(&program, Dialect::Pascal),
+            "program p;\nvar\n    a: boolean;\nbegin\n    a := a;\n    <error>\nend.\n"
+        );
+        assert_eq!(
+            print_program(&program, Dialect::C),
+            "bool a;\na := a;\n<error>;\n"
+        );
+        assert_eq!(
+            print_program(
+                &Program {
+                    decls: Vec::new(),
+                    body: sp(Vec::new()),
+                    ..program
+                },
+                Dialect::Pascal
+            ),
+            "program p;\nbegin\nend.\n"
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Traversals of statements and expressions.
+//!
+//! Each trait method defaults to the matching `walk_*` or `fold_*` function,
+//! which recurses into the children of the node. An implementation overrides
+//! only the methods for the nodes it cares about, calling the default
+//! function from there to keep descending.
+
+use crate::{Expr, Spanned, Stmt};
+
+/// Visits a tree by shared reference.
+pub trait Visitor {
+    fn visit_stmt(&mut self, stmt: &Spanned<Stmt>) {
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_expr(&mut self, expr: &Spanned<Expr>) {
+        walk_expr(self, expr);
+    }
+}
+
+/// Visits the children of `stmt` in source order.
+pub fn walk_stmt<V: Visitor + ?Sized>(visitor: &mut V, stmt: &Spanned<Stmt>) {
+    match &stmt.node {
+        Stmt::If(e, s) | Stmt::While(e, s) => {
+            visitor.visit_expr(e);
+            visitor.visit_stmt(s);
+        }
+        Stmt::IfElse(e, s, o) => {
+            visitor.visit_expr(e);
+            visitor.visit_stmt(s);
+            visitor.visit_stmt(o);
+        }
+        Stmt::Assign(_, e) | Stmt::Expr(e) => visitor.visit_expr(e),
+        Stmt::Block(ss) => {
+            for s in ss {
+                visitor.visit_stmt(s);
+            }
+        }
+        Stmt::VarDecl(_, _) | Stmt::Error => {}
+    }
+}
+
+/// Visits the operands of `expr` in source order.
+pub fn walk_expr<V: Visitor + ?Sized>(visitor: &mut V, expr: &Spanned<Expr>) {
+    match &expr.node {
+        Expr::Op(_, lhs, rhs) => {
+            visitor.visit_expr(lhs);
+            visitor.visit_expr(rhs);
+        }
+        Expr::Not(e) => visitor.visit_expr(e),
+        Expr::Id(_) | Expr::Num(_) => {}
+    }
+}
+
+/// Visits a tree by mutable reference, for rewriting it in place.
+pub trait VisitorMut {
+    fn visit_stmt_mut(&mut self, stmt: &mut Spanned<Stmt>) {
+        walk_stmt_mut(self, stmt);
+    }
+
+    fn visit_expr_mut(&mut self, expr: &mut Spanned<Expr>) {
+        walk_expr_mut(self, expr);
+    }
+}
+
+/// Visits the children of `stmt` in source order.
+pub fn walk_stmt_mut<V: VisitorMut + ?Sized>(visitor: &mut V, stmt: &mut Spanned<Stmt>) {
+    match &mut stmt.node {
+        Stmt::If(e, s) | Stmt::While(e, s) => {
+            visitor.visit_expr_mut(e);
+            visitor.visit_stmt_mut(s);
+        }
+        Stmt::IfElse(e, s, o) => {
+            visitor.visit_expr_mut(e);
+            visitor.visit_stmt_mut(s);
+            visitor.visit_stmt_mut(o);
+        }
+        Stmt::Assign(_, e) | Stmt::Expr(e) => visitor.visit_expr_mut(e),
+        Stmt::Block(ss) => {
+            for s in ss {
+                visitor.visit_stmt_mut(s);
+            }
+        }
+        Stmt::VarDecl(_, _) | Stmt::Error => {}
+    }
+}
+
+/// Visits the operands of `expr` in source order.
+pub fn walk_expr_mut<V: VisitorMut + ?Sized>(visitor: &mut V, expr: &mut Spanned<Expr>) {
+    match &mut expr.node {
+        Expr::Op(_, lhs, rhs) => {
+            visitor.visit_expr_mut(lhs);
+            visitor.visit_expr_mut(rhs);
+        }
+        Expr::Not(e) => visitor.visit_expr_mut(e),
+        Expr::Id(_) | Expr::Num(_) => {}
+    }
+}
+
+/// Transforms a tree by value, rebuilding each node from its folded
+/// children.
+pub trait Fold {
+    fn fold_stmt(&mut self, stmt: Spanned<Stmt>) -> Spanned<Stmt> {
+        fold_stmt(self, stmt)
+    }
+
+    fn fold_expr(&mut self, expr: Spanned<Expr>) -> Spanned<Expr> {
+        fold_expr(self, expr)
+    }
+}
+
+/// Folds a boxed node, reusing the allocation.
+fn fold_box<T>(
+    mut x: Box<Spanned<T>>,
+    f: impl FnOnce(Spanned<T>) -> Spanned<T>,
+) -> Box<Spanned<T>> {
+    *x = f(*x);
+    x
+}
+
+/// Folds the children of `stmt` in source order, keeping its span.
+pub fn fold_stmt<F: Fold + ?Sized>(folder: &mut F, stmt: Spanned<Stmt>) -> Spanned<Stmt> {
+    let node = match stmt.node {
+        Stmt::If(e, s) => Stmt::If(
+            fold_box(e, |x| folder.fold_expr(x)),
+            fold_box(s, |x| folder.fold_stmt(x)),
+        ),
+        Stmt::IfElse(e, s, o) => Stmt::IfElse(
+            fold_box(e, |x| folder.fold_expr(x)),
+            fold_box(s, |x| folder.fold_stmt(x)),
+            fold_box(o, |x| folder.fold_stmt(x)),
+        ),
+        Stmt::While(e, s) => Stmt::While(
+            fold_box(e, |x| folder.fold_expr(x)),
+            fold_box(s, |x| folder.fold_stmt(x)),
+        ),
+        Stmt::Assign(i, e) => Stmt::Assign(i, fold_box(e, |x| folder.fold_expr(x))),
+        Stmt::Block(ss) => Stmt::Block(ss.into_iter().map(|x| folder.fold_stmt(x)).collect()),
+        Stmt::Expr(e) => Stmt::Expr(fold_box(e, |x| folder.fold_expr(x))),
+        node @ (Stmt::VarDecl(_, _) | Stmt::Error) => node,
+    };
+    Spanned { node, ..stmt }
+}
+
+/// Folds the operands of `expr` in source order, keeping its span.
+pub fn fold_expr<F: Fold + ?Sized>(folder: &mut F, expr: Spanned<Expr>) -> Spanned<Expr> {
+    let node = match expr.node {
+        Expr::Op(op, lhs, rhs) => Expr::Op(
+            op,
+            fold_box(lhs, |x| folder.fold_expr(x)),
+            fold_box(rhs, |x| folder.fold_expr(x)),
+        ),
+        Expr::Not(e) => Expr::Not(fold_box(e, |x| folder.fold_expr(x))),
+        node @ (Expr::Id(_) | Expr::Num(_)) => node,
+    };
+    Spanned { node, ..expr }
+}
@@ -1,6 +0,0 @@
-fn main() {
-    lalrpop::Configuration::new()
-        .generate_in_source_tree()
-        .process()
-        .unwrap();
-}
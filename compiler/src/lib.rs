@@ -1,6 +0,0 @@
-pub mod ast;
-
-#[rustfmt::skip]
-#[allow(dead_code)]
-#[allow(clippy::all)]
-pub mod grammar;
@@ -1,10 +1,18 @@
-use std::{collections::HashMap, env, fmt::Display, fs::read_to_string, process::exit};
+use std::{
+    collections::{HashMap, HashSet},
+    env,
+    fmt::Display,
+    fs::{read_to_string, write},
+    process::exit,
+};
 
-use compiler::{
-    ast::{Expr, Opcode, Stmt, Type},
-    grammar::StmtListParser,
+use ast::{
+    dump::{dump_program, dump_stmt_list, Format},
+    printer::{print_program, print_stmt_list},
+    visit::{walk_expr, walk_stmt, Visitor},
+    Expr, Opcode, Program, Span, Spanned, Stmt, Type,
 };
-use lalrpop_util::ParseError;
+use parser::{has_comments, is_program, parse_program, parse_stmt_list, Dialect, Error, Parsed};
 
 type Quad = (&'static str, QuadItem, QuadItem, QuadItem);
 
@@ -119,15 +127,48 @@ impl State {
     }
 }
 
-fn visit_stmt(current: &Stmt, state: &mut State) {
-    match current {
+/// Finds the first use of an undeclared variable, in source order.
+#[derive(Debug, Default)]
+struct Undefined {
+    declared: HashSet<String>,
+    first: Option<(String, Span)>,
+}
+
+impl Undefined {
+    fn check(&mut self, var: &str, span: Span) {
+        if self.first.is_none() && !self.declared.contains(var) {
+            self.first = Some((var.to_string(), span));
+        }
+    }
+}
+
+impl Visitor for Undefined {
+    fn visit_stmt(&mut self, stmt: &Spanned<Stmt>) {
+        match &stmt.node {
+            Stmt::VarDecl(vars, _) => self.declared.extend(vars.iter().cloned()),
+            Stmt::Assign(var, _) => {
+                self.check(&var.node, var.span);
+                walk_stmt(self, stmt);
+            }
+            _ => walk_stmt(self, stmt),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Spanned<Expr>) {
+        if let Expr::Id(id) = &expr.node {
+            self.check(id, expr.span);
+        }
+        walk_expr(self, expr);
+    }
+}
+
+fn visit_stmt(current: &Spanned<Stmt>, state: &mut State) {
+    match &current.node {
         Stmt::If(e, ts) => {
             let (val, _) = visit_expr(e, state);
             if let QuadItem::TFList(t, f) = val {
                 state.fill_list(t, state.quad_id() as u32);
-                for s in ts {
-                    visit_stmt(s, state);
-                }
+                visit_stmt(ts, state);
                 let next = state.emit("j", (), (), f);
                 state.fill_list(next, state.quad_id() as u32);
             } else {
@@ -138,14 +179,10 @@ fn visit_stmt(current: &Stmt, state: &mut State) {
             let (val, _) = visit_expr(e, state);
             if let QuadItem::TFList(t, f) = val {
                 state.fill_list(t, state.quad_id() as u32);
-                for s in ts {
-                    visit_stmt(s, state);
-                }
+                visit_stmt(ts, state);
                 let next = state.emit("j", (), (), 0usize);
                 state.fill_list(f, state.quad_id() as u32);
-                for s in fs {
-                    visit_stmt(s, state);
-                }
+                visit_stmt(fs, state);
                 state.fill_list(next, state.quad_id() as u32);
             } else {
                 panic!()
@@ -155,36 +192,28 @@ fn visit_stmt(current: &Stmt, state: &mut State) {
             let (val, entry) = visit_expr(e, state);
             if let QuadItem::TFList(t, f) = val {
                 state.fill_list(t, state.quad_id() as u32);
-                for s in ts {
-                    visit_stmt(s, state);
-                }
+                visit_stmt(ts, state);
                 state.emit("j", (), (), entry);
                 state.fill_list(f, state.quad_id() as u32);
             } else {
                 panic!()
             }
         }
-        Stmt::Assign((var, left, right), e) => {
-            if !state.sym_table.contains_key(var) {
-                println!("语义错误：变量 {var} 未定义\n位置：{left}~{right}");
-                exit(1);
+        Stmt::Assign(Spanned { node: var, .. }, e) => match visit_expr(e, state).0 {
+            QuadItem::Num(num) => {
+                state.emit(":=", num, (), var.clone());
             }
-            match visit_expr(e, state).0 {
-                QuadItem::Num(num) => {
-                    state.emit(":=", num, (), var.clone());
-                }
-                QuadItem::Id(id) => {
-                    state.emit(":=", id, (), var.clone());
-                }
-                QuadItem::TFList(t, f) => {
-                    let tqid = state.emit(":=", 1u32, (), var.clone());
-                    let fqid = state.emit(":=", 0u32, (), var.clone());
-                    state.fill_list(t, tqid as u32);
-                    state.fill_list(f, fqid as u32);
-                }
-                _ => panic!(),
+            QuadItem::Id(id) => {
+                state.emit(":=", id, (), var.clone());
             }
-        }
+            QuadItem::TFList(t, f) => {
+                let tqid = state.emit(":=", 1u32, (), var.clone());
+                let fqid = state.emit(":=", 0u32, (), var.clone());
+                state.fill_list(t, tqid as u32);
+                state.fill_list(f, fqid as u32);
+            }
+            _ => panic!(),
+        },
         Stmt::VarDecl(vars, ty) => {
             for var in vars {
                 state.sym_table.insert(var.clone(), *ty);
@@ -198,18 +227,13 @@ fn visit_stmt(current: &Stmt, state: &mut State) {
         Stmt::Expr(e) => {
             visit_expr(e, state);
         }
+        Stmt::Error => unreachable!("only ASTs parsed without errors are translated"),
     }
 }
 
-fn visit_expr(current: &Expr, state: &mut State) -> (QuadItem, usize) {
-    match current {
-        Expr::Id(id, left, right) => {
-            if !state.sym_table.contains_key(id) {
-                println!("语义错误：变量 {id} 未定义\n位置：{left}~{right}");
-                exit(1);
-            }
-            (QuadItem::Id(id.clone()), state.quad_id())
-        }
+fn visit_expr(current: &Spanned<Expr>, state: &mut State) -> (QuadItem, usize) {
+    match &current.node {
+        Expr::Id(id) => (QuadItem::Id(id.clone()), state.quad_id()),
         Expr::Num(num) => (QuadItem::Num(*num), state.quad_id()),
         Expr::Op(opcode, lhs, rhs) => {
             let (left_val, left_entry) = visit_expr(lhs, state);
@@ -286,65 +310,190 @@ fn visit_expr(current: &Expr, state: &mut State) -> (QuadItem, usize) {
     }
 }
 
-fn main() {
-    let mut args = env::args();
-    let path = args.nth(1).unwrap();
-    let file_contents = read_to_string(path).unwrap();
-    let parser = StmtListParser::new();
-    let mut state = State::default();
+fn report(errors: Vec<Error>) {
+    for e in errors {
+        match e {
+            Error::Lexer(e) => println!("词法错误：{e}"),
+            e => println!("语法错误：{e}"),
+        }
+    }
+}
+
+/// Parses `src` in `dialect`, as a program if it has a `program` header and
+/// otherwise as a list of statements.
+fn parse(src: &str, dialect: Dialect) -> Parsed<Source> {
+    if is_program(src, dialect) {
+        parse_program(src).map(Source::Program)
+    } else {
+        parse_stmt_list(src, dialect).map(Source::Stmts)
+    }
+}
+
+#[derive(Debug)]
+enum Source {
+    Program(Program),
+    Stmts(Vec<Spanned<Stmt>>),
+}
 
-    match parser.parse(&file_contents) {
-        Ok(ast) => {
-            for stmt in &ast {
-                visit_stmt(stmt, &mut state);
+impl Source {
+    /// Returns the statements to translate, with the declaration section of
+    /// a program turned into declaration statements.
+    fn into_stmts(self) -> Vec<Spanned<Stmt>> {
+        match self {
+            Source::Program(program) => program
+                .decls
+                .into_iter()
+                .map(|x| Spanned {
+                    node: Stmt::VarDecl(x.node.names, x.node.ty),
+                    span: x.span,
+                })
+                .chain(program.body.node)
+                .collect(),
+            Source::Stmts(stmts) => stmts,
+        }
+    }
+}
+
+/// Formats each of `paths` in place, or with `--check` only reports the files
+/// that are not formatted.
+fn fmt(mut args: impl Iterator<Item = String>) {
+    let usage = || -> ! {
+        println!("用法：compiler fmt [--check] [--dialect pascal|c] <文件>...");
+        exit(2);
+    };
+    let mut check = false;
+    let mut dialect = Dialect::C;
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--check" => check = true,
+            "--dialect" => {
+                dialect = args
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_else(|| usage())
             }
-            println!("符号表：");
-            for (k, v) in &state.sym_table {
-                if !k.starts_with('$') {
-                    println!(
-                        "{k:>10} {:>5}",
-                        match v {
-                            Type::Bool => "bool",
-                            Type::Int => "int",
-                        }
-                    )
-                }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        usage();
+    }
+
+    let mut failed = false;
+    for path in paths {
+        let file_contents = read_to_string(&path).unwrap();
+        let formatted = match parse(&file_contents, dialect).into_result() {
+            Ok(Source::Program(program)) => print_program(&program, dialect),
+            Ok(Source::Stmts(stmts)) => print_stmt_list(&stmts, dialect),
+            Err(errors) => {
+                println!("{path}：");
+                report(errors);
+                failed = true;
+                continue;
             }
-            println!("四元式序列：");
-            for (i, (op, a, b, r)) in state.quads.iter().enumerate() {
-                println!("{}:  ({op:<3}, {a:>4}, {b:>4}, {r:>4})", i + 100);
+        };
+        if formatted == file_contents {
+            continue;
+        }
+        // the AST holds no comments, so formatting would delete them
+        if has_comments(&file_contents, dialect) {
+            println!("{path}：包含注释，格式化会删除注释，未格式化");
+            failed = true;
+        } else if check {
+            println!("{path}：格式不正确");
+            failed = true;
+        } else {
+            write(&path, formatted).unwrap();
+        }
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().is_some_and(|x| x == "fmt") {
+        args.next();
+        fmt(args);
+        return;
+    }
+    let usage = || -> ! {
+        println!(
+            "用法：compiler [--dialect pascal|c] [--emit ast=json|sexp|dot|tree] <文件>\n      compiler fmt [--check] [--dialect pascal|c] <文件>..."
+        );
+        exit(2);
+    };
+    let mut dialect = Dialect::C;
+    let mut emit = None;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--dialect" => {
+                dialect = args
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "--emit" => {
+                let format = args
+                    .next()
+                    .and_then(|x| x.strip_prefix("ast=")?.parse::<Format>().ok());
+                emit = Some(format.unwrap_or_else(|| usage()));
             }
-            println!("{}:  (nop,    _,    _,    _)", state.quad_id());
+            _ => path = Some(arg),
         }
-        Err(e) => {
-            match e {
-                ParseError::InvalidToken { location } => {
-                    println!("语法错误：无效的 token\n位置：{location}");
-                }
-                ParseError::UnrecognizedEof { location, expected } => {
-                    println!(
-                        "语法错误：预料之外的 EOF，语法不完整，期望 {}\n位置：{location}",
-                        expected.join(", ")
-                    );
-                }
-                ParseError::UnrecognizedToken { token, expected } => {
-                    println!(
-                        "语法错误：预料之外的 \"{}\"，期望 {}\n位置：{}~{}",
-                        token.1 .1,
-                        expected.join(", "),
-                        token.0,
-                        token.2
-                    );
-                }
-                ParseError::ExtraToken { token } => {
-                    println!(
-                        "语法错误：预料之外的 \"{}\"，此处不应有此 token\n位置：{}~{}",
-                        token.1 .1, token.0, token.2
-                    );
+    }
+    let Some(path) = path else { usage() };
+    let file_contents = read_to_string(path).unwrap();
+    let mut state = State::default();
+
+    let parsed = parse(&file_contents, dialect);
+    if let Some(format) = emit {
+        match &parsed.ast {
+            Some(Source::Program(program)) => print!("{}", dump_program(program, format)),
+            Some(Source::Stmts(stmts)) => print!("{}", dump_stmt_list(stmts, format)),
+            None => {}
+        }
+    }
+    if !parsed.errors.is_empty() {
+        report(parsed.errors);
+        exit(1);
+    }
+    if emit.is_some() {
+        return;
+    }
+    let stmts = parsed.ast.unwrap().into_stmts();
+    let mut undefined = Undefined::default();
+    for stmt in &stmts {
+        undefined.visit_stmt(stmt);
+    }
+    if let Some((var, span)) = undefined.first {
+        println!(
+            "语义错误：变量 {var} 未定义\n位置：{}~{}",
+            span.start, span.end
+        );
+        exit(1);
+    }
+    for stmt in &stmts {
+        visit_stmt(stmt, &mut state);
+    }
+    println!("符号表：");
+    for (k, v) in &state.sym_table {
+        if !k.starts_with('$') {
+            println!(
+                "{k:>10} {:>5}",
+                match v {
+                    Type::Bool => "bool",
+                    Type::Int => "int",
                 }
-                _ => unreachable!(),
-            }
-            exit(1);
+            )
         }
     }
+    println!("四元式序列：");
+    for (i, (op, a, b, r)) in state.quads.iter().enumerate() {
+        println!("{}:  ({op:<3}, {a:>4}, {b:>4}, {r:>4})", i + 100);
+    }
+    println!("{}:  (nop,    _,    _,    _)", state.quad_id());
 }
@@ -0,0 +1,78 @@
+//! Compares the throughput of the reader-based [`Lexer`], the slice-based
+//! [`SliceLexer`] and the generated [`TokenDfa`] on a generated program of a
+//! few megabytes.
+//!
+//! Run with `cargo bench -p lexer`.
+
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use lexer::{Lexer, SliceLexer, SymbolTable, TokenDfa};
+
+const ROUNDS: u32 = 5;
+
+fn generate_program(statements: usize) -> String {
+    let mut program = String::from("program stress;\nvar ");
+    for i in 0..100 {
+        write!(program, "v{i}, ").unwrap();
+    }
+    program.push_str("result: integer;\nbegin\n");
+    for i in 0..statements {
+        writeln!(
+            program,
+            "    if v{} <> {i} then v{} := (v{} + {}) * result - 7 // step {i}",
+            i % 100,
+            (i * 7) % 100,
+            (i * 13) % 100,
+            i % 1000,
+        )
+        .unwrap();
+    }
+    program.push_str("end.\n");
+    program
+}
+
+fn measure(name: &str, bytes: usize, mut f: impl FnMut() -> usize) {
+    let mut best = Duration::MAX;
+    let mut tokens = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        tokens = black_box(f());
+        best = best.min(start.elapsed());
+    }
+    let throughput = bytes as f64 / best.as_secs_f64() / (1024.0 * 1024.0);
+    println!("{name:>8}: {tokens} tokens in {best:?}, {throughput:.1} MiB/s");
+}
+
+fn main() {
+    let program = generate_program(100_000);
+    println!("input: {} bytes", program.len());
+
+    measure("reader", program.len(), || {
+        let lexer = Lexer::new(program.as_bytes()).unwrap();
+        lexer.fold(0, |n, x| {
+            black_box(x.unwrap());
+            n + 1
+        })
+    });
+
+    measure("slice", program.len(), || {
+        let mut symbols = SymbolTable::new();
+        let lexer = SliceLexer::new(&program, &mut symbols);
+        lexer.fold(0, |n, x| {
+            black_box(x.unwrap());
+            n + 1
+        })
+    });
+
+    let dfa = TokenDfa::default();
+    measure("dfa", program.len(), || {
+        dfa.tokens(&program).fold(0, |n, x| {
+            black_box(x.unwrap());
+            n + 1
+        })
+    });
+}
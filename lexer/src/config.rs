@@ -0,0 +1,205 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::Token;
+
+/// How reserved words are matched against identifiers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeywordCase {
+    /// Only the lowercase spelling is a keyword, e.g. `begin`.
+    Sensitive,
+    /// Any spelling is a keyword, e.g. `begin`, `BeGiN`.
+    Insensitive,
+    /// The lowercase, capitalized and uppercase spellings are keywords, e.g.
+    /// `begin`, `Begin` and `BEGIN`.
+    #[default]
+    Legacy,
+}
+
+/// Configuration of a [`Lexer`](crate::Lexer).
+#[derive(Debug, Clone)]
+pub struct LexerConfig {
+    pub keyword_case: KeywordCase,
+    pub max_number: u32,
+    pub recover: bool,
+    /// Whether `{ ... }` is a comment rather than a pair of braces.
+    pub brace_comments: bool,
+    /// Whether comments are yielded as [`Token::Comment`] instead of skipped.
+    pub keep_comments: bool,
+    /// Whether identifiers may contain Unicode characters, following the
+    /// `XID_Start` and `XID_Continue` properties.
+    pub unicode_identifiers: bool,
+    /// Whether string and character literals use C-style backslash escapes
+    /// and may be double-quoted, instead of Pascal-style `''` doubling.
+    pub c_escapes: bool,
+    keywords: HashMap<String, Token>,
+}
+
+impl LexerConfig {
+    /// Creates a configuration without any keywords.
+    pub fn empty() -> Self {
+        Self {
+            keyword_case: KeywordCase::default(),
+            max_number: u32::MAX,
+            recover: false,
+            brace_comments: false,
+            keep_comments: false,
+            unicode_identifiers: false,
+            c_escapes: false,
+            keywords: HashMap::new(),
+        }
+    }
+
+    pub fn keyword_case(mut self, keyword_case: KeywordCase) -> Self {
+        self.keyword_case = keyword_case;
+        self
+    }
+
+    pub fn max_number(mut self, max_number: u32) -> Self {
+        self.max_number = max_number;
+        self
+    }
+
+    pub fn recover(mut self, recover: bool) -> Self {
+        self.recover = recover;
+        self
+    }
+
+    pub fn brace_comments(mut self, brace_comments: bool) -> Self {
+        self.brace_comments = brace_comments;
+        self
+    }
+
+    pub fn keep_comments(mut self, keep_comments: bool) -> Self {
+        self.keep_comments = keep_comments;
+        self
+    }
+
+    pub fn unicode_identifiers(mut self, unicode_identifiers: bool) -> Self {
+        self.unicode_identifiers = unicode_identifiers;
+        self
+    }
+
+    pub fn c_escapes(mut self, c_escapes: bool) -> Self {
+        self.c_escapes = c_escapes;
+        self
+    }
+
+    /// Creates the configuration of the C-like dialect, which matches keywords
+    /// case-sensitively and declares variables with `int` and `bool` instead
+    /// of `program`, `var`, `integer` and `boolean`.
+    pub fn c_like() -> Self {
+        ["program", "begin", "end", "var", "integer", "boolean"]
+            .into_iter()
+            .fold(Self::default(), Self::remove_keyword)
+            .keyword_case(KeywordCase::Sensitive)
+            .keyword("int", Token::Int)
+            .keyword("bool", Token::Bool)
+    }
+
+    /// Registers a keyword, replacing any previous keyword with the same
+    /// spelling. The spelling is stored in lowercase.
+    pub fn keyword(mut self, spelling: &str, token: Token) -> Self {
+        self.keywords.insert(spelling.to_ascii_lowercase(), token);
+        self
+    }
+
+    /// Removes a keyword, so that it is lexed as an identifier.
+    pub fn remove_keyword(mut self, spelling: &str) -> Self {
+        self.keywords.remove(&spelling.to_ascii_lowercase());
+        self
+    }
+
+    /// Returns the keywords in lowercase, in no particular order.
+    pub(crate) fn keywords(&self) -> impl Iterator<Item = (&str, &Token)> {
+        self.keywords
+            .iter()
+            .map(|(spelling, token)| (&spelling[..], token))
+    }
+
+    /// Looks up the keyword spelled as `word` under the current case policy.
+    pub fn lookup(&self, word: &str) -> Option<Token> {
+        let lower = if word.bytes().any(|x| x.is_ascii_uppercase()) {
+            Cow::Owned(word.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(word)
+        };
+        let token = self.keywords.get(&lower[..])?;
+        let matched = match self.keyword_case {
+            KeywordCase::Sensitive => word == lower,
+            KeywordCase::Insensitive => true,
+            KeywordCase::Legacy => {
+                word == lower
+                    || word == lower.to_ascii_uppercase()
+                    || word.as_bytes()[1..] == lower.as_bytes()[1..]
+                        && word.as_bytes()[0].is_ascii_uppercase()
+            }
+        };
+        matched.then(|| token.clone())
+    }
+}
+
+impl Default for LexerConfig {
+    fn default() -> Self {
+        Self::empty()
+            .keyword("program", Token::Program)
+            .keyword("begin", Token::Begin)
+            .keyword("end", Token::End)
+            .keyword("var", Token::Var)
+            .keyword("integer", Token::Integer)
+            .keyword("boolean", Token::Bool)
+            .keyword("if", Token::If)
+            .keyword("then", Token::Then)
+            .keyword("else", Token::Else)
+            .keyword("do", Token::Do)
+            .keyword("while", Token::While)
+            .keyword("not", Token::Not)
+            .keyword("and", Token::And)
+            .keyword("or", Token::Or)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn keyword_case() {
+        let config = LexerConfig::default();
+        assert_eq!(config.lookup("Program"), Some(Token::Program));
+        assert_eq!(config.lookup("PROGRAM"), Some(Token::Program));
+        assert_eq!(config.lookup("pRoGrAm"), None);
+        assert_eq!(config.lookup("pROGRAM"), None);
+
+        let config = config.keyword_case(KeywordCase::Insensitive);
+        assert_eq!(config.lookup("pRoGrAm"), Some(Token::Program));
+
+        let config = config.keyword_case(KeywordCase::Sensitive);
+        assert_eq!(config.lookup("program"), Some(Token::Program));
+        assert_eq!(config.lookup("Program"), None);
+    }
+
+    #[test]
+    fn extra_keywords() {
+        let config = LexerConfig::empty().keyword("Mul", Token::Star);
+        assert_eq!(config.lookup("mul"), Some(Token::Star));
+        assert_eq!(config.lookup("begin"), None);
+
+        let config = LexerConfig::default().remove_keyword("not");
+        assert_eq!(config.lookup("not"), None);
+    }
+
+    #[test]
+    fn dialect_keywords() {
+        let config = LexerConfig::default();
+        assert_eq!(config.lookup("int"), None);
+        assert_eq!(config.lookup("bool"), None);
+        assert_eq!(config.lookup("Boolean"), Some(Token::Bool));
+
+        let config = LexerConfig::c_like();
+        assert_eq!(config.lookup("int"), Some(Token::Int));
+        assert_eq!(config.lookup("bool"), Some(Token::Bool));
+        assert_eq!(config.lookup("begin"), None);
+        assert_eq!(config.lookup("boolean"), None);
+        assert_eq!(config.lookup("If"), None);
+    }
+}
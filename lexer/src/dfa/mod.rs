@@ -0,0 +1,542 @@
+//! A table-driven lexer generated from regular expressions.
+//!
+//! A list of [`Rules`] is compiled into a nondeterministic automaton with
+//! Thompson's construction, turned into a [`Dfa`] with the subset
+//! construction and then minimized with Hopcroft's algorithm. The resulting
+//! transition table drives a [`DfaLexer`], which always takes the longest
+//! match and, among rules matching the same length, the one added first.
+//!
+//! [`TokenDfa`] uses this to reproduce the [`Token`](crate::Token) stream of
+//! the hand-written [`Lexer`](crate::Lexer).
+
+mod nfa;
+mod regex;
+mod token;
+
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+};
+
+use crate::{is_utf8_continuation, literal::Cursor, Error, Position, Result, SpannedToken};
+
+use nfa::Nfa;
+pub use regex::RegexError;
+use regex::{ByteSet, Regex};
+pub use token::{DfaTokens, TokenDfa, TokenKind};
+
+/// An ordered list of `(pattern, kind)` rules to generate a lexer from.
+///
+/// See [`Rules::rule`] for the pattern syntax.
+#[derive(Debug, Clone)]
+pub struct Rules<K> {
+    rules: Vec<(String, K)>,
+}
+
+impl<K> Default for Rules<K> {
+    fn default() -> Self {
+        Self { rules: Vec::new() }
+    }
+}
+
+impl<K: Clone> Rules<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule producing `kind` for input matching `pattern`. Rules added
+    /// earlier take priority over later ones matching the same input.
+    ///
+    /// Patterns support literal characters, `.` for any byte except a
+    /// newline, classes such as `[a-z_]` or `[^'\n]`, grouping with `(...)`,
+    /// alternation with `|` and the repetitions `*`, `+` and `?`.
+    /// Metacharacters are escaped with `\`, and `\n`, `\r`, `\t` and `\xHH`
+    /// stand for the corresponding bytes.
+    pub fn rule(mut self, pattern: impl Into<String>, kind: K) -> Self {
+        self.rules.push((pattern.into(), kind));
+        self
+    }
+
+    /// Compiles the rules into a minimal [`Dfa`].
+    pub fn build(&self) -> std::result::Result<Dfa<K>, RegexError> {
+        let regexes = self
+            .rules
+            .iter()
+            .map(|(pattern, _)| Regex::parse(pattern))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let nfa = Nfa::new(&regexes);
+        let dfa = Dfa {
+            patterns: self.rules.iter().map(|(x, _)| x.clone()).collect(),
+            kinds: self.rules.iter().map(|(_, x)| x.clone()).collect(),
+            ..Dfa::from_nfa(&nfa)
+        };
+        Ok(dfa.minimize())
+    }
+}
+
+/// A deterministic finite automaton over bytes, as built by [`Rules::build`].
+///
+/// Bytes are grouped into classes which no rule distinguishes, so the
+/// transition table has a column per class rather than per byte. State `0`
+/// is the start state, and missing transitions lead to a dead state.
+///
+/// The [`Display`] implementation dumps the byte classes and the transition
+/// table.
+#[derive(Debug, Clone)]
+pub struct Dfa<K> {
+    classes: [u8; 256],
+    class_count: usize,
+    /// `class_count` entries per state.
+    transitions: Vec<Option<u32>>,
+    /// The index of the rule accepted in each state.
+    accepts: Vec<Option<usize>>,
+    patterns: Vec<String>,
+    kinds: Vec<K>,
+}
+
+impl<K> Dfa<K> {
+    /// Returns the number of states.
+    pub fn len(&self) -> usize {
+        self.accepts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accepts.is_empty()
+    }
+
+    /// Returns the state reached from `state` on `byte`.
+    pub fn next_state(&self, state: usize, byte: u8) -> Option<usize> {
+        let class = self.classes[byte as usize] as usize;
+        self.transitions[state * self.class_count + class].map(|x| x as usize)
+    }
+
+    /// Returns the kind accepted in `state`, if any.
+    pub fn accepts(&self, state: usize) -> Option<&K> {
+        self.accepts[state].map(|x| &self.kinds[x])
+    }
+
+    /// Returns a lexer over `src` driven by this automaton.
+    pub fn lexer<'dfa, 'src>(&'dfa self, src: &'src str) -> DfaLexer<'dfa, 'src, K> {
+        DfaLexer {
+            dfa: self,
+            src,
+            offset: 0,
+            position: Position::default(),
+        }
+    }
+
+    /// Runs the subset construction. The result accepts rule indices and has
+    /// no patterns or kinds yet.
+    fn from_nfa(nfa: &Nfa) -> Self {
+        let (classes, class_count) = byte_classes(nfa);
+        let mut representatives = vec![0u8; class_count];
+        for x in (0..=255u8).rev() {
+            representatives[classes[x as usize] as usize] = x;
+        }
+
+        let mut start = vec![nfa.start];
+        nfa.closure(&mut start);
+        let mut ids = HashMap::from([(start.clone(), 0)]);
+        let mut sets = vec![start];
+        let mut transitions = Vec::new();
+        let mut accepts = Vec::new();
+        let mut i = 0;
+        while i < sets.len() {
+            accepts.push(sets[i].iter().filter_map(|&x| nfa.states[x].accept).min());
+            for &x in &representatives {
+                let mut next: Vec<_> = sets[i]
+                    .iter()
+                    .filter_map(|&s| match nfa.states[s].on {
+                        Some((set, target)) if set.contains(x) => Some(target),
+                        _ => None,
+                    })
+                    .collect();
+                if next.is_empty() {
+                    transitions.push(None);
+                    continue;
+                }
+                nfa.closure(&mut next);
+                let id = *ids.entry(next).or_insert_with_key(|next| {
+                    sets.push(next.clone());
+                    sets.len() - 1
+                });
+                transitions.push(Some(id as u32));
+            }
+            i += 1;
+        }
+
+        Self {
+            classes,
+            class_count,
+            transitions,
+            accepts,
+            patterns: Vec::new(),
+            kinds: Vec::new(),
+        }
+    }
+
+    /// Merges equivalent states with Hopcroft's algorithm, then drops states
+    /// from which nothing can be accepted.
+    fn minimize(self) -> Self {
+        // make the automaton complete, with an explicit dead state `n`
+        let n = self.len();
+        let target = |state: usize, class: usize| match state {
+            _ if state == n => n,
+            _ => self.transitions[state * self.class_count + class].map_or(n, |x| x as usize),
+        };
+        let mut inverse = vec![vec![Vec::new(); n + 1]; self.class_count];
+        for (class, inverse) in inverse.iter_mut().enumerate() {
+            for state in 0..=n {
+                inverse[target(state, class)].push(state);
+            }
+        }
+
+        // start with states grouped by the rule they accept
+        let mut blocks: Vec<Vec<usize>> = Vec::new();
+        let mut initial = HashMap::new();
+        let mut block_of: Vec<_> = (0..=n)
+            .map(|state| {
+                let accept = self.accepts.get(state).copied().flatten();
+                let block = *initial.entry(accept).or_insert_with(|| {
+                    blocks.push(Vec::new());
+                    blocks.len() - 1
+                });
+                blocks[block].push(state);
+                block
+            })
+            .collect();
+
+        let mut pending: Vec<_> = (0..blocks.len()).collect();
+        let mut is_pending = vec![true; blocks.len()];
+        while let Some(splitter) = pending.pop() {
+            is_pending[splitter] = false;
+            let splitter = blocks[splitter].clone();
+            for inverse in &inverse {
+                let mut hits: HashMap<usize, Vec<usize>> = HashMap::new();
+                for &state in &splitter {
+                    for &source in &inverse[state] {
+                        hits.entry(block_of[source]).or_default().push(source);
+                    }
+                }
+                for (block, mut hit) in hits {
+                    hit.sort_unstable();
+                    hit.dedup();
+                    if hit.len() == blocks[block].len() {
+                        continue;
+                    }
+                    let new = blocks.len();
+                    blocks[block].retain(|x| hit.binary_search(x).is_err());
+                    for &state in &hit {
+                        block_of[state] = new;
+                    }
+                    let smaller = if hit.len() < blocks[block].len() {
+                        new
+                    } else {
+                        block
+                    };
+                    blocks.push(hit);
+                    is_pending.push(false);
+                    // a pending block is split into two pending ones, otherwise
+                    // splitting by the smaller half is enough
+                    let x = if is_pending[block] { new } else { smaller };
+                    if !is_pending[x] {
+                        is_pending[x] = true;
+                        pending.push(x);
+                    }
+                }
+            }
+        }
+
+        // number the live blocks in breadth-first order from the start state
+        let dead = block_of[n];
+        let mut ids = vec![None; blocks.len()];
+        let mut order = vec![block_of[0]];
+        ids[block_of[0]] = Some(0u32);
+        let mut i = 0;
+        while i < order.len() {
+            let state = blocks[order[i]][0];
+            for class in 0..self.class_count {
+                let block = block_of[target(state, class)];
+                if block != dead && ids[block].is_none() {
+                    ids[block] = Some(order.len() as u32);
+                    order.push(block);
+                }
+            }
+            i += 1;
+        }
+
+        let mut transitions = Vec::with_capacity(order.len() * self.class_count);
+        let mut accepts = Vec::with_capacity(order.len());
+        for &block in &order {
+            let state = blocks[block][0];
+            accepts.push(self.accepts[state]);
+            for class in 0..self.class_count {
+                transitions.push(ids[block_of[target(state, class)]]);
+            }
+        }
+        Self {
+            transitions,
+            accepts,
+            ..self
+        }
+    }
+}
+
+/// Groups bytes which no transition of `nfa` distinguishes, returning the
+/// class of each byte and the number of classes.
+fn byte_classes(nfa: &Nfa) -> ([u8; 256], usize) {
+    let sets: BTreeSet<_> = nfa
+        .states
+        .iter()
+        .filter_map(|x| x.on.map(|(set, _)| set))
+        .collect();
+    let mut signatures = HashMap::new();
+    let mut classes = [0; 256];
+    for x in 0..=255u8 {
+        let signature: Vec<_> = sets.iter().map(|set| set.contains(x)).collect();
+        let count = signatures.len();
+        classes[x as usize] = *signatures.entry(signature).or_insert(count) as u8;
+    }
+    (classes, signatures.len())
+}
+
+impl<K> Display for Dfa<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "rules:")?;
+        for (i, pattern) in self.patterns.iter().enumerate() {
+            writeln!(f, "{i:>5}  {pattern}")?;
+        }
+
+        writeln!(f, "byte classes:")?;
+        for class in 0..self.class_count {
+            let mut set = ByteSet::default();
+            for x in 0..=255u8 {
+                if self.classes[x as usize] as usize == class {
+                    set.insert(x);
+                }
+            }
+            writeln!(f, "{class:>5}  {set}")?;
+        }
+
+        writeln!(f, "transitions:")?;
+        write!(f, "{:>5} {:>6} |", "state", "accept")?;
+        for class in 0..self.class_count {
+            write!(f, " {class:>4}")?;
+        }
+        writeln!(f)?;
+        for state in 0..self.len() {
+            let accept = self.accepts[state].map_or("-".to_string(), |x| x.to_string());
+            write!(f, "{state:>5} {accept:>6} |")?;
+            for class in 0..self.class_count {
+                match self.transitions[state * self.class_count + class] {
+                    Some(x) => write!(f, " {x:>4}")?,
+                    None => write!(f, " {:>4}", "-")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A piece of input matched by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lexeme<'src, K> {
+    pub kind: K,
+    pub text: &'src str,
+}
+
+/// A lexer over an in-memory source, driven by a [`Dfa`].
+///
+/// Input not matched by any rule is reported as [`Error::InvalidChar`] and
+/// skipped one character at a time.
+#[derive(Debug, Clone)]
+pub struct DfaLexer<'dfa, 'src, K> {
+    dfa: &'dfa Dfa<K>,
+    src: &'src str,
+    offset: usize,
+    position: Position,
+}
+
+impl<'src, K: Clone> DfaLexer<'_, 'src, K> {
+    /// Returns the position of the next lexeme.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the byte offset of the next lexeme.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn advance_to(&mut self, offset: usize) {
+        for &x in &self.src.as_bytes()[self.offset..offset] {
+            match x {
+                b'\n' => {
+                    self.position.line += 1;
+                    self.position.column = 0;
+                }
+                x if !is_utf8_continuation(x) => self.position.column += 1,
+                _ => {}
+            }
+        }
+        self.offset = offset;
+    }
+
+    fn next_lexeme(&mut self) -> Option<Result<SpannedToken<Lexeme<'src, K>>>> {
+        let bytes = &self.src.as_bytes()[self.offset..];
+        if bytes.is_empty() {
+            return None;
+        }
+
+        // run the automaton as far as possible, remembering the last accept
+        let mut state = 0;
+        let mut last = None;
+        for (i, &x) in bytes.iter().enumerate() {
+            match self.dfa.next_state(state, x) {
+                Some(next) => state = next,
+                None => break,
+            }
+            if let Some(rule) = self.dfa.accepts[state] {
+                last = Some((rule, i + 1));
+            }
+        }
+
+        let start = self.position;
+        let start_offset = self.offset;
+        let Some((rule, len)) = last else {
+            let c = self.src[self.offset..].chars().next().unwrap();
+            self.advance_to(self.offset + c.len_utf8());
+            return Some(Err(Error::InvalidChar(c, start)));
+        };
+        self.advance_to(self.offset + len);
+        Some(Ok(SpannedToken {
+            token: Lexeme {
+                kind: self.dfa.kinds[rule].clone(),
+                text: &self.src[start_offset..self.offset],
+            },
+            start,
+            end: self.position,
+            byte_range: start_offset..self.offset,
+        }))
+    }
+}
+
+impl<K: Clone> Cursor for DfaLexer<'_, '_, K> {
+    fn peek_char(&mut self) -> Result<Option<char>> {
+        Ok(self.src[self.offset..].chars().next())
+    }
+
+    fn bump_char(&mut self, c: char) -> Result<()> {
+        self.advance_to(self.offset + c.len_utf8());
+        Ok(())
+    }
+
+    fn peek_byte(&mut self, n: usize) -> Result<Option<u8>> {
+        Ok(self.src.as_bytes().get(self.offset + n).copied())
+    }
+
+    fn bump_byte(&mut self) -> Result<()> {
+        self.advance_to(self.offset + 1);
+        Ok(())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl<'src, K: Clone> Iterator for DfaLexer<'_, 'src, K> {
+    type Item = Result<SpannedToken<Lexeme<'src, K>>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_lexeme()
+    }
+}
+
+impl<K: Clone> FusedIterator for DfaLexer<'_, '_, K> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn lexemes<'a>(dfa: &Dfa<&'a str>, src: &'a str) -> Vec<(&'a str, &'a str)> {
+        dfa.lexer(src)
+            .map(|x| x.unwrap().token)
+            .map(|x| (x.kind, x.text))
+            .collect()
+    }
+
+    #[test]
+    fn minimize() {
+        // the textbook example, whose minimal automaton has four live states
+        let dfa = Rules::new().rule("(a|b)*abb", ()).build().unwrap();
+        assert_eq!(dfa.len(), 4);
+        let accepting: Vec<_> = (0..dfa.len())
+            .filter(|&x| dfa.accepts(x).is_some())
+            .collect();
+        assert_eq!(accepting.len(), 1);
+
+        // equivalent rules give the same automaton
+        let dfa = Rules::new().rule("a+|aa*", ()).build().unwrap();
+        assert_eq!(dfa.len(), 2);
+    }
+
+    #[test]
+    fn longest_match_and_priority() {
+        let dfa = Rules::new()
+            .rule("if", "if")
+            .rule("[a-z]+", "id")
+            .rule("[0-9]+(\\.[0-9]+)?", "num")
+            .rule("\\.", "dot")
+            .rule("[ ]+", "ws")
+            .build()
+            .unwrap();
+        assert_eq!(
+            lexemes(&dfa, "if iff 1.5 2."),
+            [
+                ("if", "if"),
+                ("ws", " "),
+                ("id", "iff"),
+                ("ws", " "),
+                ("num", "1.5"),
+                ("ws", " "),
+                ("num", "2"),
+                ("dot", "."),
+            ]
+        );
+
+        let mut lexer = dfa.lexer("a?\nb");
+        lexer.next().unwrap().unwrap();
+        assert!(matches!(
+            lexer.next().unwrap(),
+            Err(Error::InvalidChar('?', Position { line: 1, column: 1 }))
+        ));
+        let token = lexer.nth(1).unwrap().unwrap();
+        assert_eq!(token.start, Position::new(2, 0));
+        assert_eq!(token.byte_range, 3..4);
+    }
+
+    #[test]
+    fn dump_table() {
+        let dfa = Rules::new().rule("a", 0).rule("[a-z]+", 1).build().unwrap();
+        let dump = dfa.to_string();
+        assert_eq!(
+            dump,
+            "rules:
+    0  a
+    1  [a-z]+
+byte classes:
+    0  \\x00-`{-\\xff
+    1  a
+    2  b-z
+transitions:
+state accept |    0    1    2
+    0      - |    -    1    2
+    1      0 |    -    2    2
+    2      1 |    -    2    2
+"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use super::regex::{ByteSet, Regex};
+
+/// A state of an [`Nfa`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NfaState {
+    pub(crate) epsilon: Vec<usize>,
+    /// A transition on any byte in the set.
+    pub(crate) on: Option<(ByteSet, usize)>,
+    /// The index of the rule accepted in this state.
+    pub(crate) accept: Option<usize>,
+}
+
+/// A nondeterministic finite automaton built with Thompson's construction,
+/// accepting any of a list of rules.
+#[derive(Debug, Clone)]
+pub(crate) struct Nfa {
+    pub(crate) states: Vec<NfaState>,
+    pub(crate) start: usize,
+}
+
+impl Nfa {
+    /// Builds an automaton accepting each of `rules` with its index.
+    pub(crate) fn new<'a>(rules: impl IntoIterator<Item = &'a Regex>) -> Self {
+        let mut nfa = Self {
+            states: vec![NfaState::default()],
+            start: 0,
+        };
+        for (i, regex) in rules.into_iter().enumerate() {
+            let (start, end) = nfa.fragment(regex);
+            nfa.states[end].accept = Some(i);
+            nfa.states[0].epsilon.push(start);
+        }
+        nfa
+    }
+
+    fn add_state(&mut self) -> usize {
+        self.states.push(NfaState::default());
+        self.states.len() - 1
+    }
+
+    /// Adds the states for `regex`, returning its start and end state.
+    fn fragment(&mut self, regex: &Regex) -> (usize, usize) {
+        match regex {
+            Regex::Empty => {
+                let state = self.add_state();
+                (state, state)
+            }
+            Regex::Set(set) => {
+                let start = self.add_state();
+                let end = self.add_state();
+                self.states[start].on = Some((*set, end));
+                (start, end)
+            }
+            Regex::Concat(items) => {
+                let (start, mut end) = self.fragment(&items[0]);
+                for item in &items[1..] {
+                    let (next_start, next_end) = self.fragment(item);
+                    self.states[end].epsilon.push(next_start);
+                    end = next_end;
+                }
+                (start, end)
+            }
+            Regex::Alt(alternatives) => {
+                let start = self.add_state();
+                let end = self.add_state();
+                for alternative in alternatives {
+                    let (x, y) = self.fragment(alternative);
+                    self.states[start].epsilon.push(x);
+                    self.states[y].epsilon.push(end);
+                }
+                (start, end)
+            }
+            Regex::Star(inner) | Regex::Plus(inner) | Regex::Optional(inner) => {
+                let start = self.add_state();
+                let end = self.add_state();
+                let (x, y) = self.fragment(inner);
+                self.states[start].epsilon.push(x);
+                self.states[y].epsilon.push(end);
+                if !matches!(regex, Regex::Plus(_)) {
+                    self.states[start].epsilon.push(end);
+                }
+                if !matches!(regex, Regex::Optional(_)) {
+                    self.states[y].epsilon.push(x);
+                }
+                (start, end)
+            }
+        }
+    }
+
+    /// Extends `states` to its epsilon closure. The result is sorted.
+    pub(crate) fn closure(&self, states: &mut Vec<usize>) {
+        let mut seen = vec![false; self.states.len()];
+        let mut stack = states.clone();
+        states.clear();
+        while let Some(state) = stack.pop() {
+            if seen[state] {
+                continue;
+            }
+            seen[state] = true;
+            states.push(state);
+            stack.extend(&self.states[state].epsilon);
+        }
+        states.sort_unstable();
+    }
+}
@@ -0,0 +1,329 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use thiserror::Error;
+
+/// A set of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub(crate) struct ByteSet([u64; 4]);
+
+impl ByteSet {
+    pub(crate) fn single(x: u8) -> Self {
+        let mut set = Self::default();
+        set.insert(x);
+        set
+    }
+
+    pub(crate) fn range(from: u8, to: u8) -> Self {
+        let mut set = Self::default();
+        for x in from..=to {
+            set.insert(x);
+        }
+        set
+    }
+
+    pub(crate) fn insert(&mut self, x: u8) {
+        self.0[x as usize / 64] |= 1 << (x % 64);
+    }
+
+    pub(crate) fn contains(&self, x: u8) -> bool {
+        self.0[x as usize / 64] & 1 << (x % 64) != 0
+    }
+
+    pub(crate) fn union(mut self, other: Self) -> Self {
+        for (x, y) in self.0.iter_mut().zip(other.0) {
+            *x |= y;
+        }
+        self
+    }
+
+    pub(crate) fn complement(mut self) -> Self {
+        for x in &mut self.0 {
+            *x = !*x;
+        }
+        self
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0 == [0; 4]
+    }
+}
+
+impl Display for ByteSet {
+    /// Writes the set as a list of ranges, e.g. `0-9A-F_`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut x = 0usize;
+        while x < 256 {
+            if !self.contains(x as u8) {
+                x += 1;
+                continue;
+            }
+            let start = x;
+            while x + 1 < 256 && self.contains(x as u8 + 1) {
+                x += 1;
+            }
+            write_byte(f, start as u8)?;
+            if x > start {
+                if x > start + 1 {
+                    f.write_str("-")?;
+                }
+                write_byte(f, x as u8)?;
+            }
+            x += 1;
+        }
+        Ok(())
+    }
+}
+
+fn write_byte(f: &mut Formatter<'_>, x: u8) -> FmtResult {
+    match x {
+        b'\\' | b'-' => write!(f, "\\{}", x as char),
+        b'!'..=b'~' => write!(f, "{}", x as char),
+        _ => write!(f, "\\x{x:02x}"),
+    }
+}
+
+/// A parsed regular expression over bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Regex {
+    /// Matches the empty string.
+    Empty,
+    Set(ByteSet),
+    Concat(Vec<Regex>),
+    Alt(Vec<Regex>),
+    Star(Box<Regex>),
+    Plus(Box<Regex>),
+    Optional(Box<Regex>),
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("invalid regular expression `{pattern}` at byte {offset}: {message}")]
+pub struct RegexError {
+    pub pattern: String,
+    pub offset: usize,
+    pub message: &'static str,
+}
+
+impl Regex {
+    /// Parses `pattern`.
+    ///
+    /// The syntax is a small subset of the usual one: literal characters,
+    /// `.` for any byte except a newline, classes such as `[a-z_]` or
+    /// `[^'\n]`, grouping with `(...)`, alternation with `|` and the
+    /// repetitions `*`, `+` and `?`. Metacharacters are escaped with `\`, and
+    /// `\n`, `\r`, `\t` and `\xHH` stand for the corresponding bytes.
+    /// Non-ASCII characters match their UTF-8 encoding.
+    pub(crate) fn parse(pattern: &str) -> Result<Self, RegexError> {
+        let mut parser = Parser {
+            pattern,
+            bytes: pattern.as_bytes(),
+            offset: 0,
+        };
+        let regex = parser.alternation()?;
+        match parser.peek() {
+            None => Ok(regex),
+            Some(_) => Err(parser.error("unmatched `)`")),
+        }
+    }
+}
+
+struct Parser<'a> {
+    pattern: &'a str,
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.offset).copied()
+    }
+
+    fn bump(&mut self) -> Option<u8> {
+        let x = self.peek()?;
+        self.offset += 1;
+        Some(x)
+    }
+
+    fn error(&self, message: &'static str) -> RegexError {
+        RegexError {
+            pattern: self.pattern.to_string(),
+            offset: self.offset,
+            message,
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Regex, RegexError> {
+        let mut alternatives = vec![self.concatenation()?];
+        while self.peek() == Some(b'|') {
+            self.bump();
+            alternatives.push(self.concatenation()?);
+        }
+        Ok(match alternatives.len() {
+            1 => alternatives.pop().unwrap(),
+            _ => Regex::Alt(alternatives),
+        })
+    }
+
+    fn concatenation(&mut self) -> Result<Regex, RegexError> {
+        let mut items = Vec::new();
+        while !matches!(self.peek(), None | Some(b'|' | b')')) {
+            items.push(self.repetition()?);
+        }
+        Ok(match items.len() {
+            0 => Regex::Empty,
+            1 => items.pop().unwrap(),
+            _ => Regex::Concat(items),
+        })
+    }
+
+    fn repetition(&mut self) -> Result<Regex, RegexError> {
+        let mut regex = self.atom()?;
+        loop {
+            regex = match self.peek() {
+                Some(b'*') => Regex::Star(Box::new(regex)),
+                Some(b'+') => Regex::Plus(Box::new(regex)),
+                Some(b'?') => Regex::Optional(Box::new(regex)),
+                _ => return Ok(regex),
+            };
+            self.bump();
+        }
+    }
+
+    fn atom(&mut self) -> Result<Regex, RegexError> {
+        match self.peek().unwrap() {
+            b'(' => {
+                self.bump();
+                let regex = self.alternation()?;
+                if self.bump() != Some(b')') {
+                    return Err(self.error("unclosed group"));
+                }
+                Ok(regex)
+            }
+            b'[' => self.class().map(Regex::Set),
+            b'.' => {
+                self.bump();
+                Ok(Regex::Set(ByteSet::single(b'\n').complement()))
+            }
+            b'*' | b'+' | b'?' => Err(self.error("repetition without an operand")),
+            b'\\' => self.escape().map(|x| Regex::Set(ByteSet::single(x))),
+            _ => {
+                // a literal character, matching its UTF-8 encoding
+                let c = self.pattern[self.offset..].chars().next().unwrap();
+                self.offset += c.len_utf8();
+                let mut buf = [0; 4];
+                let mut items: Vec<_> = c
+                    .encode_utf8(&mut buf)
+                    .bytes()
+                    .map(|x| Regex::Set(ByteSet::single(x)))
+                    .collect();
+                Ok(match items.len() {
+                    1 => items.pop().unwrap(),
+                    _ => Regex::Concat(items),
+                })
+            }
+        }
+    }
+
+    fn class(&mut self) -> Result<ByteSet, RegexError> {
+        self.bump();
+        let negated = self.peek() == Some(b'^');
+        if negated {
+            self.bump();
+        }
+        let mut set = ByteSet::default();
+        loop {
+            let from = match self.peek() {
+                None => return Err(self.error("unclosed class")),
+                Some(b']') => break,
+                Some(b'\\') => self.escape()?,
+                Some(x) if x.is_ascii() => {
+                    self.bump();
+                    x
+                }
+                Some(_) => return Err(self.error("non-ASCII character in class")),
+            };
+            if self.peek() != Some(b'-') || self.bytes.get(self.offset + 1) == Some(&b']') {
+                set.insert(from);
+                continue;
+            }
+            self.bump();
+            let to = match self.peek() {
+                Some(b'\\') => self.escape()?,
+                Some(x) if x.is_ascii() => {
+                    self.bump();
+                    x
+                }
+                _ => return Err(self.error("invalid range in class")),
+            };
+            if to < from {
+                return Err(self.error("invalid range in class"));
+            }
+            set = set.union(ByteSet::range(from, to));
+        }
+        self.bump();
+        if negated {
+            set = set.complement();
+        }
+        if set.is_empty() {
+            return Err(self.error("empty class"));
+        }
+        Ok(set)
+    }
+
+    fn escape(&mut self) -> Result<u8, RegexError> {
+        self.bump();
+        Ok(match self.bump() {
+            Some(b'n') => b'\n',
+            Some(b'r') => b'\r',
+            Some(b't') => b'\t',
+            Some(b'x') => {
+                let digits = self.pattern.get(self.offset..self.offset + 2);
+                let x = digits
+                    .and_then(|x| u8::from_str_radix(x, 16).ok())
+                    .ok_or_else(|| self.error("invalid `\\x` escape"))?;
+                self.offset += 2;
+                x
+            }
+            Some(x) if x.is_ascii_punctuation() => x,
+            _ => return Err(self.error("invalid escape")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let a = || Regex::Set(ByteSet::single(b'a'));
+        assert_eq!(Regex::parse("a").unwrap(), a());
+        assert_eq!(
+            Regex::parse("a|(a)*").unwrap(),
+            Regex::Alt(vec![a(), Regex::Star(Box::new(a()))])
+        );
+        assert_eq!(
+            Regex::parse("[a-c_]").unwrap(),
+            Regex::Set(ByteSet::range(b'a', b'c').union(ByteSet::single(b'_')))
+        );
+        assert_eq!(Regex::parse("\\x41").unwrap(), Regex::parse("A").unwrap());
+        assert_eq!(
+            Regex::parse("[^\\x00-\\xff]").unwrap_err().message,
+            "empty class"
+        );
+        assert_eq!(Regex::parse("(a").unwrap_err().message, "unclosed group");
+        assert_eq!(Regex::parse("a)").unwrap_err().offset, 1);
+        assert_eq!(
+            Regex::parse("+").unwrap_err().message,
+            "repetition without an operand"
+        );
+    }
+
+    #[test]
+    fn display_set() {
+        let set = ByteSet::range(b'0', b'9')
+            .union(ByteSet::single(b'_'))
+            .union(ByteSet::range(b'a', b'b'));
+        assert_eq!(set.to_string(), "0-9_ab");
+        assert_eq!(ByteSet::single(b'\n').to_string(), "\\x0a");
+    }
+}
@@ -0,0 +1,348 @@
+use std::iter::FusedIterator;
+
+use super::{Dfa, DfaLexer, Lexeme, Rules};
+use crate::{
+    literal::{self, Cursor, StrCursor},
+    Error, KeywordCase, LexerConfig, Position, Result, SpannedToken, Token,
+};
+
+/// What a rule of a [`TokenDfa`] produces.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// Always the given token, e.g. for keywords and operators.
+    Fixed(Token),
+    Identifier,
+    /// A number literal, converted as by the hand-written lexer.
+    Number,
+    /// A string or character literal, possibly unterminated.
+    Quoted,
+    Comment,
+    UnterminatedComment,
+    Whitespace,
+}
+
+const OPERATORS: [(&str, Token); 19] = [
+    (":=", Token::Assign),
+    (">=", Token::GreaterEqual),
+    ("<=", Token::LessEqual),
+    ("<>", Token::NotEqual),
+    ("+", Token::Plus),
+    ("-", Token::Minus),
+    ("*", Token::Star),
+    ("/", Token::Slash),
+    ("(", Token::LeftParen),
+    (")", Token::RightParen),
+    ("{", Token::LeftBrace),
+    ("}", Token::RightBrace),
+    ("=", Token::Equal),
+    (">", Token::GreaterThan),
+    ("<", Token::LessThan),
+    (";", Token::Semicolon),
+    (",", Token::Comma),
+    (".", Token::Dot),
+    (":", Token::Colon),
+];
+
+/// A generated lexer producing the same tokens as [`Lexer`](crate::Lexer)
+/// under a given [`LexerConfig`].
+///
+/// Identifiers are always ASCII, i.e. [`LexerConfig::unicode_identifiers`] is
+/// not supported.
+#[derive(Debug, Clone)]
+pub struct TokenDfa {
+    dfa: Dfa<TokenKind>,
+    config: LexerConfig,
+}
+
+impl TokenDfa {
+    pub fn new(config: LexerConfig) -> Self {
+        let dfa = Self::rules(&config)
+            .build()
+            .expect("token rules should be valid");
+        Self { dfa, config }
+    }
+
+    /// Returns the rules describing the tokens under `config`.
+    ///
+    /// Keywords are rules of their own, taking priority over identifiers.
+    pub fn rules(config: &LexerConfig) -> Rules<TokenKind> {
+        let mut rules = Rules::new()
+            .rule("[ \\t\\n\\x0c\\r]+", TokenKind::Whitespace)
+            .rule("//[^\\n\\r]*", TokenKind::Comment)
+            .rule("\\(\\*([^*]|\\*+[^*)])*\\*+\\)", TokenKind::Comment)
+            .rule(
+                "\\(\\*([^*]|\\*+[^*)])*\\**",
+                TokenKind::UnterminatedComment,
+            );
+        if config.brace_comments {
+            rules = rules
+                .rule("\\{[^}]*\\}", TokenKind::Comment)
+                .rule("\\{[^}]*", TokenKind::UnterminatedComment);
+        }
+
+        let mut keywords: Vec<_> = config.keywords().collect();
+        keywords.sort_unstable_by_key(|&(spelling, _)| spelling);
+        for (spelling, token) in keywords {
+            rules = rules.rule(
+                keyword_pattern(spelling, config.keyword_case),
+                TokenKind::Fixed(token.clone()),
+            );
+        }
+        rules = rules.rule("[A-Za-z_][A-Za-z0-9_]*", TokenKind::Identifier);
+
+        for pattern in [
+            "[0-9][0-9_]*(\\.[0-9][0-9_]*)?([eE][+\\-]?[0-9][0-9_]*)?",
+            "(\\$|0[xX])[0-9A-Fa-f][0-9A-Fa-f_]*",
+            "0[oO][0-7][0-7_]*",
+            "0[bB][01][01_]*",
+        ] {
+            rules = rules.rule(pattern, TokenKind::Number);
+        }
+
+        let quotes: &[char] = if config.c_escapes {
+            &['\'', '"']
+        } else {
+            &['\'']
+        };
+        for &quote in quotes {
+            let body = if config.c_escapes {
+                format!("([^{quote}\\\\\\n\\r]|\\\\[^\\n\\r])*")
+            } else {
+                "([^'\\n\\r]|'')*".to_string()
+            };
+            let trailing = if config.c_escapes { "\\\\?" } else { "" };
+            rules = rules
+                .rule(format!("{quote}{body}{quote}"), TokenKind::Quoted)
+                .rule(format!("{quote}{body}{trailing}"), TokenKind::Quoted);
+        }
+
+        for (spelling, token) in OPERATORS {
+            if !(config.brace_comments && token == Token::LeftBrace) {
+                rules = rules.rule(escape(spelling), TokenKind::Fixed(token));
+            }
+        }
+        rules
+    }
+
+    /// Returns the underlying automaton, e.g. to dump its transition table.
+    pub fn dfa(&self) -> &Dfa<TokenKind> {
+        &self.dfa
+    }
+
+    /// Returns an iterator over the tokens of `src`.
+    pub fn tokens<'dfa, 'src>(&'dfa self, src: &'src str) -> DfaTokens<'dfa, 'src> {
+        DfaTokens {
+            lexer: self.dfa.lexer(src),
+            config: &self.config,
+            errors: Vec::new(),
+        }
+    }
+}
+
+impl Default for TokenDfa {
+    fn default() -> Self {
+        Self::new(LexerConfig::default())
+    }
+}
+
+/// Escapes the metacharacters in `s`.
+fn escape(s: &str) -> String {
+    let mut buf = String::new();
+    for c in s.chars() {
+        if "\\.[]()|*+?".contains(c) {
+            buf.push('\\');
+        }
+        buf.push(c);
+    }
+    buf
+}
+
+/// Returns a pattern matching the spellings of a keyword, given in lowercase.
+fn keyword_pattern(spelling: &str, case: KeywordCase) -> String {
+    match case {
+        KeywordCase::Sensitive => escape(spelling),
+        KeywordCase::Insensitive => spelling
+            .chars()
+            .map(|c| match c {
+                'a'..='z' => format!("[{c}{}]", c.to_ascii_uppercase()),
+                c => escape(&c.to_string()),
+            })
+            .collect(),
+        KeywordCase::Legacy => {
+            let mut spellings = vec![spelling.to_string(), spelling.to_ascii_uppercase()];
+            if spelling.starts_with(|c: char| c.is_ascii_lowercase()) {
+                spellings.push(spelling[..1].to_ascii_uppercase() + &spelling[1..]);
+            }
+            spellings.dedup();
+            let spellings: Vec<_> = spellings.iter().map(|x| escape(x)).collect();
+            spellings.join("|")
+        }
+    }
+}
+
+/// An iterator over the [`SpannedToken`]s of a source, created by
+/// [`TokenDfa::tokens`].
+#[derive(Debug)]
+pub struct DfaTokens<'dfa, 'src> {
+    lexer: DfaLexer<'dfa, 'src, TokenKind>,
+    config: &'dfa LexerConfig,
+    errors: Vec<Error>,
+}
+
+impl DfaTokens<'_, '_> {
+    /// Returns the lexical errors recorded so far in recovery mode.
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Consumes the iterator, returning all lexical errors recorded in
+    /// recovery mode.
+    pub fn into_errors(self) -> Vec<Error> {
+        self.errors
+    }
+
+    /// Converts a lexeme starting at `start`, or returns `None` if it is
+    /// skipped.
+    fn convert(&self, lexeme: Lexeme<'_, TokenKind>, start: Position) -> Result<Option<Token>> {
+        let text = lexeme.text;
+        Ok(Some(match lexeme.kind {
+            TokenKind::Fixed(token) => token,
+            TokenKind::Identifier => Token::Id(text.to_string()),
+            TokenKind::Number => {
+                literal::scan_number(&mut StrCursor::new(text, start), self.config.max_number)?
+            }
+            TokenKind::Quoted => {
+                literal::scan_quoted(&mut StrCursor::new(text, start), self.config.c_escapes)?
+            }
+            TokenKind::Comment if self.config.keep_comments => Token::Comment(text.to_string()),
+            TokenKind::Comment | TokenKind::Whitespace => return Ok(None),
+            TokenKind::UnterminatedComment => return Err(Error::UnterminatedComment(start)),
+        }))
+    }
+
+    fn next_spanned_token(&mut self) -> Result<Option<SpannedToken>> {
+        loop {
+            let start = self.lexer.position();
+            let start_offset = self.lexer.offset();
+            let token = match self.lexer.next() {
+                None => return Ok(None),
+                Some(x) => x.and_then(|x| self.convert(x.token, start)),
+            };
+            let token = match token {
+                Ok(Some(token)) => token,
+                Ok(None) => continue,
+                Err(e) if self.config.recover => {
+                    self.lexer
+                        .skip_invalid(&e, self.config.unicode_identifiers)?;
+                    self.errors.push(e);
+                    Token::Error
+                }
+                Err(e) => return Err(e),
+            };
+            return Ok(Some(SpannedToken {
+                token,
+                start,
+                end: self.lexer.position(),
+                byte_range: start_offset..self.lexer.offset(),
+            }));
+        }
+    }
+}
+
+impl Iterator for DfaTokens<'_, '_> {
+    type Item = Result<SpannedToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_spanned_token().transpose()
+    }
+}
+
+impl FusedIterator for DfaTokens<'_, '_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_util::Lcg, Lexer};
+
+    fn assert_same_tokens(input: &str, dfa: &TokenDfa) {
+        let to_strings = |x: Result<SpannedToken>| x.map_err(|e| e.to_string());
+        let mut lexer = Lexer::new(input.as_bytes())
+            .unwrap()
+            .with_config(dfa.config.clone())
+            .spanned();
+        let expected: Vec<_> = lexer.by_ref().map(to_strings).collect();
+        let expected_errors: Vec<_> = lexer
+            .into_inner()
+            .into_errors()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+
+        let mut tokens = dfa.tokens(input);
+        let actual: Vec<_> = tokens.by_ref().map(to_strings).collect();
+        let errors: Vec<_> = tokens.errors().iter().map(|e| e.to_string()).collect();
+
+        assert_eq!(actual, expected, "{input:?}");
+        assert_eq!(errors, expected_errors, "{input:?}");
+    }
+
+    #[test]
+    fn same_as_lexer() {
+        let input = "program p; (* c **) var a1, _b: integer; { x }\n\
+                     begin a1 := (a1 + 42) * 3; if a1 <> 0 then _b := a1 / 2 end. // e\n\
+                     Begin BEGIN bEgIn 1.5 2e-3 1_000 $ff 0x1F 0o7 0b1 1. 'it''s' 'c'";
+        let configs = [
+            LexerConfig::default(),
+            LexerConfig::default()
+                .brace_comments(true)
+                .keep_comments(true),
+            LexerConfig::default().keyword_case(KeywordCase::Insensitive),
+            LexerConfig::default()
+                .keyword_case(KeywordCase::Sensitive)
+                .keyword("loop", Token::While),
+            LexerConfig::default().c_escapes(true),
+        ];
+        for config in configs {
+            assert_same_tokens(input, &TokenDfa::new(config.clone()));
+            assert_same_tokens(input, &TokenDfa::new(config.recover(true)));
+        }
+
+        let config = LexerConfig::default().recover(true);
+        let dfa = TokenDfa::new(config.clone());
+        assert_same_tokens("a，b ? 99999999999 1__2 $ $g (* x", &dfa);
+        assert_same_tokens("'open\n\"x\" 'a", &dfa);
+        let dfa = TokenDfa::new(config.clone().c_escapes(true));
+        assert_same_tokens("'\\q' \"a\\x4\" 'b\\", &dfa);
+        assert_same_tokens("{ open", &TokenDfa::new(config.brace_comments(true)));
+    }
+
+    #[test]
+    fn random_input() {
+        let pieces = [
+            " ", "\n", "a", "B", "1", "0x", "e", ".", "_", ":", "=", "(*", "*)", "*", "{", "}",
+            "//", "begin", "，", "<", ">", "'", "\"", "\\", "$", "?",
+        ];
+        let mut rng = Lcg::new(42);
+        let dfas: Vec<_> = [(false, false), (true, false), (false, true), (true, true)]
+            .into_iter()
+            .map(|(brace_comments, c_escapes)| {
+                let config = LexerConfig::default()
+                    .recover(true)
+                    .brace_comments(brace_comments)
+                    .c_escapes(c_escapes);
+                TokenDfa::new(config)
+            })
+            .collect();
+        for i in 0..500 {
+            let input: String = (0..rng.below(30)).map(|_| rng.pick(&pieces)).collect();
+            assert_same_tokens(&input, &dfas[i % dfas.len()]);
+        }
+    }
+
+    #[test]
+    fn keyword_patterns() {
+        assert_eq!(keyword_pattern("if", KeywordCase::Legacy), "if|IF|If");
+        assert_eq!(keyword_pattern("if", KeywordCase::Insensitive), "[iI][fF]");
+        assert_eq!(keyword_pattern("if", KeywordCase::Sensitive), "if");
+    }
+}
@@ -0,0 +1,234 @@
+use std::ops::Range;
+
+use crate::{Lexer, LexerConfig, Position, SpannedToken};
+
+/// The number of bytes past its end that the lexer may look at to end a
+/// token, e.g. the `e+` in `1e+5` after a `1` that turns out to be a number.
+const LOOKAHEAD: usize = 2;
+
+/// A source text together with its tokens, supporting incremental re-lexing
+/// after edits.
+///
+/// The buffer always lexes in recovery mode, so invalid input shows up as
+/// [`Token::Error`](crate::Token::Error) rather than stopping the lexer.
+#[derive(Debug, Clone)]
+pub struct TokenBuffer {
+    source: String,
+    tokens: Vec<SpannedToken>,
+    config: LexerConfig,
+}
+
+impl TokenBuffer {
+    pub fn new(source: impl Into<String>) -> Self {
+        Self::with_config(source, LexerConfig::default())
+    }
+
+    pub fn with_config(source: impl Into<String>, config: LexerConfig) -> Self {
+        let mut buffer = Self {
+            source: source.into(),
+            tokens: Vec::new(),
+            config: config.recover(true),
+        };
+        buffer.tokens = buffer
+            .lex_from(Position::default(), 0)
+            .map(|x| x.unwrap())
+            .collect();
+        buffer
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn tokens(&self) -> &[SpannedToken] {
+        &self.tokens
+    }
+
+    /// Replaces the bytes in `range` with `new_text` and re-lexes the affected
+    /// part of the source.
+    ///
+    /// Lexing restarts at the first token whose lookahead reaches the edit and
+    /// stops as soon as a new token ends where an old token ended after the
+    /// edit, since the rest of the stream is then unchanged. Returns the range of indices into
+    /// [`TokenBuffer::tokens`] that were replaced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds or does not lie on character
+    /// boundaries.
+    pub fn apply_edit(&mut self, range: Range<usize>, new_text: &str) -> Range<usize> {
+        self.source.replace_range(range.clone(), new_text);
+        let delta = new_text.len() as isize - range.len() as isize;
+        let edit_end = range.start + new_text.len();
+
+        // tokens whose lookahead ends before the edit cannot be affected, but a
+        // token ending near it may be extended, e.g. `1.` followed by an
+        // inserted `5` becomes `1.5`
+        let first = self
+            .tokens
+            .partition_point(|x| x.byte_range.end + LOOKAHEAD < range.start);
+        let (position, offset) = match self.tokens.get(first) {
+            Some(token) if token.byte_range.start <= range.start => {
+                (token.start, token.byte_range.start)
+            }
+            _ => match first.checked_sub(1).map(|x| &self.tokens[x]) {
+                Some(token) => (token.end, token.byte_range.end),
+                None => (Position::default(), 0),
+            },
+        };
+
+        let mut new_tokens = Vec::new();
+        let mut resync = None;
+        for token in self.lex_from(position, offset) {
+            let token = token.unwrap();
+            let end = token.byte_range.end;
+            new_tokens.push(token);
+            if end < edit_end {
+                continue;
+            }
+            let old_end = (end as isize - delta) as usize;
+            let old = &self.tokens[first..];
+            let i = old.partition_point(|x| x.byte_range.end < old_end);
+            if old.get(i).is_some_and(|x| x.byte_range.end == old_end) {
+                resync = Some(first + i);
+                break;
+            }
+        }
+
+        let last = match resync {
+            Some(last) => {
+                let old_end = self.tokens[last].end;
+                let new_end = new_tokens.last().unwrap().end;
+                for token in &mut self.tokens[last + 1..] {
+                    token.start = shift(token.start, old_end, new_end);
+                    token.end = shift(token.end, old_end, new_end);
+                    token.byte_range = (token.byte_range.start as isize + delta) as usize
+                        ..(token.byte_range.end as isize + delta) as usize;
+                }
+                last + 1
+            }
+            None => self.tokens.len(),
+        };
+        let len = new_tokens.len();
+        self.tokens.splice(first..last, new_tokens);
+        first..first + len
+    }
+
+    fn lex_from(
+        &self,
+        position: Position,
+        offset: usize,
+    ) -> impl Iterator<Item = crate::Result<SpannedToken>> + '_ {
+        Lexer::new(&self.source.as_bytes()[offset..])
+            .unwrap()
+            .with_config(self.config.clone())
+            .starting_at(position, offset)
+            .spanned()
+    }
+}
+
+/// Moves `position`, which lies after `old_anchor`, along with the anchor
+/// moving to `new_anchor`.
+fn shift(position: Position, old_anchor: Position, new_anchor: Position) -> Position {
+    if position.line == old_anchor.line {
+        Position::new(
+            new_anchor.line,
+            position.column - old_anchor.column + new_anchor.column,
+        )
+    } else {
+        Position::new(
+            position.line - old_anchor.line + new_anchor.line,
+            position.column,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{test_util::Lcg, Token};
+
+    fn assert_consistent(buffer: &TokenBuffer) {
+        let expected = TokenBuffer::with_config(buffer.source(), buffer.config.clone());
+        assert_eq!(buffer.tokens(), expected.tokens(), "{:?}", buffer.source());
+    }
+
+    #[test]
+    fn edits() {
+        let mut buffer = TokenBuffer::new("var a: integer;\nbegin\n  a := 1\nend.");
+        assert_eq!(buffer.tokens().len(), 11);
+
+        // extend an identifier
+        let changed = buffer.apply_edit(5..5, "bc");
+        assert_eq!(changed, 0..2);
+        assert_eq!(buffer.tokens()[1].token, Token::Id("abc".into()));
+        assert_consistent(&buffer);
+
+        // add a line, shifting everything after it
+        let changed = buffer.apply_edit(17..17, "\n");
+        assert_eq!(changed, 3..6);
+        assert_consistent(&buffer);
+
+        // open a comment swallowing the rest of the source
+        let changed = buffer.apply_edit(0..0, "(* ");
+        assert_eq!(changed, 0..1);
+        assert_eq!(buffer.tokens().len(), 1);
+        assert_eq!(buffer.tokens()[0].token, Token::Error);
+        assert_consistent(&buffer);
+
+        let changed = buffer.apply_edit(0..3, "");
+        assert_eq!(changed, 0..11);
+        assert_consistent(&buffer);
+
+        // merge `:` and `=` across deleted whitespace
+        let mut buffer = TokenBuffer::new("a : = 1; b := 2");
+        let changed = buffer.apply_edit(3..4, "");
+        assert_eq!(changed, 0..2);
+        assert_eq!(buffer.tokens()[1].token, Token::Assign);
+        assert_consistent(&buffer);
+    }
+
+    #[test]
+    fn lookahead_edits() {
+        // the inserted digit completes a number started before the last token
+        for (source, offset, digit, expected) in [
+            ("a := 1. ", 7, "5", Token::Real(1.5)),
+            ("a := 0x ", 7, "1", Token::Number(1)),
+            ("a := 1e ", 7, "5", Token::Real(1e5)),
+            ("a := 1e+ ", 8, "5", Token::Real(1e5)),
+        ] {
+            let mut buffer = TokenBuffer::new(source);
+            buffer.apply_edit(offset..offset, digit);
+            assert_eq!(buffer.tokens()[2].token, expected, "{source:?}");
+            assert_consistent(&buffer);
+        }
+    }
+
+    #[test]
+    fn random_edits() {
+        let pieces = [
+            "", " ", "\n", "a", "1", ":", "=", "(*", "*)", "{", "}", "//", "begin", "，", "<", ">",
+            "'", ".", "x", "e", "+", "0",
+        ];
+        let mut buffer = TokenBuffer::with_config(
+            "program p;\nvar x, y: integer; (* c *)\nbegin x := 1; if x <> y then y := x end.\n",
+            LexerConfig::default().brace_comments(true),
+        );
+        let mut rng = Lcg::new(12345);
+        for _ in 0..500 {
+            let boundaries: Vec<_> = buffer
+                .source()
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain([buffer.source().len()])
+                .collect();
+            let start = boundaries[rng.below(boundaries.len())];
+            let end = boundaries[rng.below(boundaries.len())];
+            let (start, end) = (start.min(end), start.max(end));
+            let end = end.min(start + 4);
+            let end = boundaries.iter().copied().find(|&x| x >= end).unwrap();
+            buffer.apply_edit(start..end, rng.pick(&pieces));
+            assert_consistent(&buffer);
+        }
+    }
+}
@@ -1,15 +1,33 @@
+pub mod config;
+pub mod dfa;
+pub mod incremental;
+mod literal;
+pub mod slice;
+pub mod symbol;
+#[cfg(test)]
+mod test_util;
 pub mod token;
 
 use std::{
-    io::{BufRead, Bytes, Error as IOError},
+    collections::VecDeque,
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{BufRead, Bytes, Error as IOError, ErrorKind},
     iter::FusedIterator,
+    ops::Range,
 };
 
+use serde::Serialize;
 use thiserror::Error;
+use unicode_xid::UnicodeXID;
 
-pub use token::Token;
+pub use config::{KeywordCase, LexerConfig};
+pub use dfa::{Dfa, DfaLexer, Rules, TokenDfa};
+pub use incremental::TokenBuffer;
+pub use slice::{InternedToken, SliceLexer};
+pub use symbol::{Symbol, SymbolTable};
+pub use token::{read_token_dump, ParseTokenError, Token};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct Position {
     pub line: u32,
     pub column: u32,
@@ -17,20 +35,86 @@ pub struct Position {
 
 #[derive(Debug, Error)]
 pub enum Error {
-    #[error("invalid byte `{0}` at line {}, column {}", .1.line, .1.column)]
+    #[error("invalid character `{}` at line {}, column {}", .0.escape_debug(), .1.line, .1.column)]
+    InvalidChar(char, Position),
+
+    #[error("invalid UTF-8 byte `{0:#04x}` at line {}, column {}", .1.line, .1.column)]
     InvalidByte(u8, Position),
 
+    #[error("number `{text}` is out of range at line {}, column {}", .span.start.line, .span.start.column)]
+    NumberOverflow { text: String, span: Range<Position> },
+
+    #[error("malformed number `{text}` at line {}, column {}", .span.start.line, .span.start.column)]
+    MalformedNumber { text: String, span: Range<Position> },
+
+    #[error("unterminated string starting at line {}, column {}", .span.start.line, .span.start.column)]
+    UnterminatedString { span: Range<Position> },
+
+    #[error("invalid escape `{escape}` at line {}, column {}", .span.start.line, .span.start.column)]
+    InvalidEscape {
+        escape: String,
+        span: Range<Position>,
+    },
+
+    #[error("character literal at line {}, column {} is not exactly one character", .span.start.line, .span.start.column)]
+    InvalidCharLiteral { span: Range<Position> },
+
+    #[error("unterminated comment starting at line {}, column {}", .0.line, .0.column)]
+    UnterminatedComment(Position),
+
+    #[error("invalid token dump at line {line}")]
+    TokenDump {
+        line: u32,
+        source: token::ParseTokenError,
+    },
+
     #[error("IO error occurred")]
     IOError(#[from] IOError),
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// A token together with its location in the source.
+///
+/// `end` and the end of `byte_range` are exclusive, i.e. they point just past
+/// the last byte of the token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpannedToken<T = Token> {
+    pub token: T,
+    pub start: Position,
+    pub end: Position,
+    pub byte_range: Range<usize>,
+}
+
 #[derive(Debug)]
 pub struct Lexer<R> {
     bytes: Bytes<R>,
     current: Option<u8>,
+    lookahead: VecDeque<u8>,
+    primed: bool,
     position: Position,
+    offset: usize,
+    config: LexerConfig,
+    errors: Vec<Error>,
+    checkpoint: Checkpoint,
+    consumed: Vec<u8>,
+}
+
+/// The state at the start of the token being scanned, which is restored when
+/// scanning is interrupted by an IO error.
+#[derive(Debug, Clone, Copy, Default)]
+struct Checkpoint {
+    current: Option<u8>,
+    position: Position,
+    offset: usize,
+}
+
+/// An iterator over the [`SpannedToken`]s of a [`Lexer`].
+///
+/// Created by [`Lexer::spanned`].
+#[derive(Debug)]
+pub struct SpannedLexer<R> {
+    lexer: Lexer<R>,
 }
 
 impl Position {
@@ -39,28 +123,184 @@ impl Position {
     }
 }
 
+impl Default for Position {
+    fn default() -> Self {
+        Self::new(1, 0)
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
 impl<R: BufRead> Lexer<R> {
+    /// Creates a lexer reading from `reader`.
+    ///
+    /// Nothing is read until the first token is requested. Reads interrupted
+    /// by [`ErrorKind::Interrupted`] are retried. Other IO errors, such as
+    /// [`ErrorKind::WouldBlock`], are returned from the iterator, and the
+    /// token being scanned is restarted on the next call, so lexing can be
+    /// resumed once the reader is ready again.
     pub fn new(reader: R) -> Result<Self> {
-        let mut bytes = reader.bytes();
-        let current = bytes.next().transpose().map_err(Error::IOError)?;
         Ok(Self {
-            bytes,
-            current,
-            position: Position::new(1, 0),
+            bytes: reader.bytes(),
+            current: None,
+            lookahead: VecDeque::new(),
+            primed: false,
+            position: Position::default(),
+            offset: 0,
+            config: LexerConfig::default(),
+            errors: Vec::new(),
+            checkpoint: Checkpoint::default(),
+            consumed: Vec::new(),
         })
     }
 
+    /// Enables error recovery.
+    ///
+    /// In this mode lexical errors do not interrupt the token stream. Instead,
+    /// the offending input is skipped, a [`Token::Error`] is yielded in its
+    /// place and the error is recorded, to be retrieved by [`Lexer::errors`]
+    /// or [`Lexer::into_errors`]. IO errors are still returned as usual.
+    pub fn with_recovery(mut self) -> Self {
+        self.config.recover = true;
+        self
+    }
+
+    /// Returns the lexical errors recorded so far in recovery mode.
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Consumes the lexer, returning all lexical errors recorded in recovery
+    /// mode.
+    pub fn into_errors(self) -> Vec<Error> {
+        self.errors
+    }
+
+    /// Sets the largest number literal accepted by this lexer.
+    ///
+    /// Larger literals are reported as [`Error::NumberOverflow`].
+    pub fn with_max_number(mut self, max_number: u32) -> Self {
+        self.config.max_number = max_number;
+        self
+    }
+
+    /// Replaces the configuration of this lexer.
+    ///
+    /// All settings are replaced, including those made by
+    /// [`Lexer::with_recovery`] and [`Lexer::with_max_number`], which are part
+    /// of the configuration. Call this first and those methods after it, or
+    /// set [`LexerConfig::recover`] and [`LexerConfig::max_number`] instead.
+    pub fn with_config(mut self, config: LexerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Turns this lexer into an iterator that also yields token locations.
+    pub fn spanned(self) -> SpannedLexer<R> {
+        SpannedLexer { lexer: self }
+    }
+
+    /// Makes reported locations start from `position` and `offset` instead of
+    /// the beginning of the file, for lexing a suffix of a source.
+    pub fn starting_at(mut self, position: Position, offset: usize) -> Self {
+        self.position = position;
+        self.offset = offset;
+        self
+    }
+
+    fn read_byte(&mut self) -> Result<Option<u8>> {
+        loop {
+            match self.bytes.next() {
+                Some(Err(e)) if e.kind() == ErrorKind::Interrupted => continue,
+                x => return x.transpose().map_err(Error::IOError),
+            }
+        }
+    }
+
     fn advance(&mut self) -> Result<()> {
-        if matches!(self.current, Some(b'\n')) {
-            self.position.line += 1;
-            self.position.column = 0;
-        } else {
-            self.position.column += 1;
+        let next = match self.lookahead.pop_front() {
+            Some(x) => Some(x),
+            None => self.read_byte()?,
+        };
+        match self.current {
+            Some(b'\n') => {
+                self.position.line += 1;
+                self.position.column = 0;
+            }
+            // columns count characters, not UTF-8 continuation bytes
+            Some(x) if !is_utf8_continuation(x) => self.position.column += 1,
+            _ => {}
         }
-        self.current = self.bytes.next().transpose().map_err(Error::IOError)?;
+        self.offset += 1;
+        self.current = next;
+        self.consumed.extend(next);
         Ok(())
     }
 
+    fn set_checkpoint(&mut self) {
+        self.checkpoint = Checkpoint {
+            current: self.current,
+            position: self.position,
+            offset: self.offset,
+        };
+        self.consumed.clear();
+    }
+
+    /// Restores the state saved by [`Lexer::set_checkpoint`], putting back all
+    /// bytes consumed since then.
+    fn rewind(&mut self) {
+        for x in self.consumed.drain(..).rev() {
+            self.lookahead.push_front(x);
+        }
+        self.current = self.checkpoint.current;
+        self.position = self.checkpoint.position;
+        self.offset = self.checkpoint.offset;
+    }
+
+    /// Returns the `n`-th byte after `current` without consuming it, starting
+    /// from 0.
+    fn peek_nth(&mut self, n: usize) -> Result<Option<u8>> {
+        while self.lookahead.len() <= n {
+            match self.read_byte()? {
+                Some(x) => self.lookahead.push_back(x),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(self.lookahead[n]))
+    }
+
+    /// Returns the byte after `current` without consuming it.
+    fn peek(&mut self) -> Result<Option<u8>> {
+        self.peek_nth(0)
+    }
+
+    /// Decodes the character starting at `current` without consuming it,
+    /// returning it along with its length in bytes.
+    fn current_char(&mut self) -> Result<Option<(char, usize)>> {
+        let Some(first) = self.current else {
+            return Ok(None);
+        };
+        let invalid = Error::InvalidByte(first, self.position);
+        let Some(len) = utf8_len(first) else {
+            return Err(invalid);
+        };
+        let mut buf = [first, 0, 0, 0];
+        for (i, x) in buf[1..len].iter_mut().enumerate() {
+            match self.peek_nth(i)? {
+                Some(byte) => *x = byte,
+                None => return Err(invalid),
+            }
+        }
+        match std::str::from_utf8(&buf[..len]) {
+            Ok(s) => Ok(s.chars().next().map(|c| (c, len))),
+            Err(_) => Err(invalid),
+        }
+    }
+
     fn skip_whitespace(&mut self) -> Result<()> {
         while self.current.is_some_and(|x| x.is_ascii_whitespace()) {
             self.advance()?;
@@ -68,67 +308,256 @@ impl<R: BufRead> Lexer<R> {
         Ok(())
     }
 
+    fn at_comment(&mut self) -> Result<bool> {
+        Ok(match self.current {
+            Some(b'/') => self.peek()? == Some(b'/'),
+            Some(b'(') => self.peek()? == Some(b'*'),
+            Some(b'{') => self.config.brace_comments,
+            _ => false,
+        })
+    }
+
+    /// Scans a `// ...`, `(* ... *)` or `{ ... }` comment, returning its text
+    /// including the delimiters.
+    fn scan_comment(&mut self) -> Result<String> {
+        let start = self.position;
+        let opening = self.current.unwrap();
+        let mut buf = vec![opening];
+        self.advance()?;
+        if opening == b'/' {
+            while self.current.is_some_and(|x| x != b'\n' && x != b'\r') {
+                buf.push(self.current.unwrap());
+                self.advance()?;
+            }
+        } else {
+            if opening == b'(' {
+                buf.push(b'*');
+                self.advance()?;
+            }
+            loop {
+                let Some(x) = self.current else {
+                    return Err(Error::UnterminatedComment(start));
+                };
+                buf.push(x);
+                self.advance()?;
+                if opening == b'{' && x == b'}' {
+                    break;
+                }
+                if opening == b'(' && x == b'*' && self.current == Some(b')') {
+                    buf.push(b')');
+                    self.advance()?;
+                    break;
+                }
+            }
+        }
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
     fn next_token(&mut self) -> Result<Option<Token>> {
-        self.skip_whitespace()?;
-        if self.current.is_none() {
-            return Ok(None);
+        Ok(self.next_spanned_token()?.map(|x| x.token))
+    }
+
+    fn next_spanned_token(&mut self) -> Result<Option<SpannedToken>> {
+        if !self.primed {
+            self.current = self.read_byte()?;
+            self.primed = true;
         }
+        loop {
+            self.skip_whitespace()?;
+            if self.current.is_none() {
+                return Ok(None);
+            }
+
+            self.set_checkpoint();
+            match self.scan_spanned_token() {
+                Ok(Some(token)) => return Ok(Some(token)),
+                Ok(None) => continue,
+                Err(e @ Error::IOError(_)) => {
+                    self.rewind();
+                    return Err(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Scans a token at `current`, or returns `None` if a comment is skipped.
+    fn scan_spanned_token(&mut self) -> Result<Option<SpannedToken>> {
+        let start = self.position;
+        let start_offset = self.offset;
+        let token = if self.at_comment()? {
+            match self.scan_comment() {
+                Ok(text) if self.config.keep_comments => Token::Comment(text),
+                Ok(_) => return Ok(None),
+                Err(e @ Error::UnterminatedComment(_)) if self.config.recover => {
+                    self.errors.push(e);
+                    Token::Error
+                }
+                Err(e) => return Err(e),
+            }
+        } else {
+            match self.scan_token() {
+                Err(
+                    e @ (Error::InvalidChar(..)
+                    | Error::InvalidByte(..)
+                    | Error::NumberOverflow { .. }
+                    | Error::MalformedNumber { .. }
+                    | Error::UnterminatedString { .. }
+                    | Error::InvalidEscape { .. }
+                    | Error::InvalidCharLiteral { .. }),
+                ) if self.config.recover => {
+                    literal::Cursor::skip_invalid(self, &e, self.config.unicode_identifiers)?;
+                    self.errors.push(e);
+                    Token::Error
+                }
+                x => x?,
+            }
+        };
+        Ok(Some(SpannedToken {
+            token,
+            start,
+            end: self.position,
+            byte_range: start_offset..self.offset,
+        }))
+    }
 
+    fn scan_token(&mut self) -> Result<Token> {
         let current = self.current.unwrap();
         let current_pos = self.position;
+        if !current.is_ascii() {
+            return self.scan_non_ascii();
+        }
+        if current == b'\'' || current == b'"' && self.config.c_escapes {
+            return literal::scan_quoted(self, self.config.c_escapes);
+        }
+        if current.is_ascii_digit() || current == b'$' {
+            return literal::scan_number(self, self.config.max_number);
+        }
         self.advance()?;
+        if let Some((token, len)) = operator(current, self.current) {
+            if len == 2 {
+                self.advance()?;
+            }
+            return Ok(token);
+        }
         match current {
-            b'+' => Ok(Some(Token::Plus)),
-            b'-' => Ok(Some(Token::Minus)),
-            b'(' => Ok(Some(Token::LeftParen)),
-            b')' => Ok(Some(Token::RightParen)),
-            b'=' => Ok(Some(Token::Equal)),
-            b'>' => Ok(Some(Token::GreaterThan)),
-            b'<' => Ok(Some(Token::LessThan)),
-            b';' => Ok(Some(Token::Semicolon)),
-            b',' => Ok(Some(Token::Comma)),
-            b':' => match self.current {
-                Some(b'=') => {
-                    self.advance()?;
-                    Ok(Some(Token::Assign))
-                }
-                _ => Ok(Some(Token::Colon)),
-            },
-            x @ b'0'..=b'9' => {
-                let mut num = (x - b'0') as u32;
-                while self.current.is_some_and(|x| x.is_ascii_digit()) {
-                    let digit = self.current.unwrap() - b'0';
-                    num = num * 10 + digit as u32;
-                    self.advance()?; // FIXME: retry capability
-                }
-                Ok(Some(Token::Number(num)))
-            }
-            x @ (b'A'..=b'Z' | b'a'..=b'z' | b'_') => {
-                let mut buf = vec![x];
-                while self
-                    .current
-                    .is_some_and(|x| x.is_ascii_alphanumeric() || x == b'_')
-                {
-                    buf.push(self.current.unwrap());
-                    self.advance()?;
+            x @ (b'A'..=b'Z' | b'a'..=b'z' | b'_') => self.scan_identifier(String::from(x as char)),
+            x => Err(Error::InvalidChar(x as char, current_pos)),
+        }
+    }
+
+    fn scan_non_ascii(&mut self) -> Result<Token> {
+        let position = self.position;
+        let (c, len) = match self.current_char() {
+            Ok(x) => x.unwrap(),
+            Err(e) => {
+                self.advance()?;
+                return Err(e);
+            }
+        };
+        for _ in 0..len {
+            self.advance()?;
+        }
+        if self.config.unicode_identifiers && c.is_xid_start() {
+            self.scan_identifier(String::from(c))
+        } else {
+            Err(Error::InvalidChar(c, position))
+        }
+    }
+
+    /// Scans the rest of an identifier or keyword starting with `word`.
+    fn scan_identifier(&mut self, mut word: String) -> Result<Token> {
+        while let Some(x) = self.current {
+            if x.is_ascii_alphanumeric() || x == b'_' {
+                word.push(x as char);
+                self.advance()?;
+            } else if !x.is_ascii() && self.config.unicode_identifiers {
+                match self.current_char() {
+                    Ok(Some((c, len))) if c.is_xid_continue() => {
+                        word.push(c);
+                        for _ in 0..len {
+                            self.advance()?;
+                        }
+                    }
+                    Err(e @ Error::IOError(_)) => return Err(e),
+                    _ => break,
                 }
-                let word = String::from_utf8(buf).unwrap();
-                Ok(Some(match &word[..] {
-                    "program" | "Program" | "PROGRAM" => Token::Program,
-                    "begin" | "Begin" | "BEGIN" => Token::Begin,
-                    "end" | "End" | "END" => Token::End,
-                    "var" | "Var" | "VAR" => Token::Var,
-                    "integer" | "Integer" | "INTEGER" => Token::Integer,
-                    "if" | "If" | "IF" => Token::If,
-                    "then" | "Then" | "THEN" => Token::Then,
-                    "else" | "Else" | "ELSE" => Token::Else,
-                    "do" | "Do" | "DO" => Token::Do,
-                    "while" | "While" | "WHILE" => Token::While,
-                    _ => Token::Id(word),
-                }))
+            } else {
+                break;
             }
-            x => Err(Error::InvalidByte(x, current_pos)),
         }
+        Ok(self.config.lookup(&word).unwrap_or(Token::Id(word)))
+    }
+}
+
+/// Returns the length of the UTF-8 sequence starting with `first`, or `None`
+/// if `first` cannot start a sequence.
+pub(crate) fn utf8_len(first: u8) -> Option<usize> {
+    match first {
+        0x00..=0x7f => Some(1),
+        0xc2..=0xdf => Some(2),
+        0xe0..=0xef => Some(3),
+        0xf0..=0xf4 => Some(4),
+        _ => None,
+    }
+}
+
+pub(crate) fn is_utf8_continuation(byte: u8) -> bool {
+    byte & 0xc0 == 0x80
+}
+
+/// Matches an operator or delimiter starting with `first`, followed by
+/// `second`. Returns the token and its length in bytes.
+pub(crate) fn operator(first: u8, second: Option<u8>) -> Option<(Token, usize)> {
+    Some(match (first, second) {
+        (b':', Some(b'=')) => (Token::Assign, 2),
+        (b'>', Some(b'=')) => (Token::GreaterEqual, 2),
+        (b'<', Some(b'=')) => (Token::LessEqual, 2),
+        (b'<', Some(b'>')) => (Token::NotEqual, 2),
+        (b'+', _) => (Token::Plus, 1),
+        (b'-', _) => (Token::Minus, 1),
+        (b'*', _) => (Token::Star, 1),
+        (b'/', _) => (Token::Slash, 1),
+        (b'(', _) => (Token::LeftParen, 1),
+        (b')', _) => (Token::RightParen, 1),
+        (b'{', _) => (Token::LeftBrace, 1),
+        (b'}', _) => (Token::RightBrace, 1),
+        (b'=', _) => (Token::Equal, 1),
+        (b'>', _) => (Token::GreaterThan, 1),
+        (b'<', _) => (Token::LessThan, 1),
+        (b';', _) => (Token::Semicolon, 1),
+        (b',', _) => (Token::Comma, 1),
+        (b'.', _) => (Token::Dot, 1),
+        (b':', _) => (Token::Colon, 1),
+        _ => return None,
+    })
+}
+
+impl<R: BufRead> literal::Cursor for Lexer<R> {
+    fn peek_char(&mut self) -> Result<Option<char>> {
+        Ok(self.current_char()?.map(|(c, _)| c))
+    }
+
+    fn bump_char(&mut self, c: char) -> Result<()> {
+        for _ in 0..c.len_utf8() {
+            self.advance()?;
+        }
+        Ok(())
+    }
+
+    fn peek_byte(&mut self, n: usize) -> Result<Option<u8>> {
+        match n {
+            0 => Ok(self.current),
+            n => self.peek_nth(n - 1),
+        }
+    }
+
+    fn bump_byte(&mut self) -> Result<()> {
+        self.advance()
+    }
+
+    fn position(&self) -> Position {
+        self.position
     }
 }
 
@@ -142,10 +571,48 @@ impl<R: BufRead> Iterator for Lexer<R> {
 
 impl<R: BufRead> FusedIterator for Lexer<R> {}
 
+impl<R: BufRead> Iterator for SpannedLexer<R> {
+    type Item = Result<SpannedToken>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lexer.next_spanned_token().transpose()
+    }
+}
+
+impl<R> SpannedLexer<R> {
+    /// Returns the underlying lexer, e.g. to retrieve recorded errors.
+    pub fn into_inner(self) -> Lexer<R> {
+        self.lexer
+    }
+}
+
+impl<R: BufRead> FusedIterator for SpannedLexer<R> {}
+
 #[cfg(test)]
 mod test {
+    use std::{
+        collections::VecDeque,
+        io::{BufReader, Read},
+    };
+
     use super::*;
 
+    /// A reader that yields the given chunks and errors one by one.
+    struct FlakyReader(VecDeque<std::result::Result<&'static [u8], ErrorKind>>);
+
+    impl Read for FlakyReader {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.0.pop_front() {
+                Some(Ok(chunk)) => {
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    Ok(chunk.len())
+                }
+                Some(Err(kind)) => Err(kind.into()),
+                None => Ok(0),
+            }
+        }
+    }
+
     #[test]
     fn tokenize() {
         let input = "  \n\tprogram;:=_aaa1_b0 vAr:VAR 1234567890 Var6".as_bytes();
@@ -166,38 +633,451 @@ mod test {
         assert_eq!(lexer.next_token().unwrap(), None);
     }
 
+    #[test]
+    fn line_comments() {
+        let input = "a // b := 1\n// c\n*not".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input).unwrap().map(|x| x.unwrap()).collect();
+
+        assert_eq!(tokens, vec![Token::Id("a".into()), Token::Star, Token::Not]);
+    }
+
+    #[test]
+    fn number_overflow() {
+        let input = "4294967295 99999999999;".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap();
+
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Number(u32::MAX)));
+        match lexer.next_token().unwrap_err() {
+            Error::NumberOverflow { text, span } => {
+                assert_eq!(text, "99999999999");
+                assert_eq!(span, Position::new(1, 11)..Position::new(1, 22));
+            }
+            _ => panic!(),
+        }
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Semicolon));
+
+        let input = "255 256".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap().with_max_number(255);
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Number(255)));
+        assert!(matches!(
+            lexer.next_token(),
+            Err(Error::NumberOverflow { .. })
+        ));
+    }
+
+    #[test]
+    fn recovery() {
+        let input = "a：=1，b?99999999999;c".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap().with_recovery();
+        let tokens: Vec<_> = lexer.by_ref().map(|x| x.unwrap()).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Id("a".into()),
+                Token::Error,
+                Token::Equal,
+                Token::Number(1),
+                Token::Error,
+                Token::Id("b".into()),
+                Token::Error,
+                Token::Error,
+                Token::Semicolon,
+                Token::Id("c".into()),
+            ]
+        );
+
+        let errors = lexer.into_errors();
+        assert_eq!(errors.len(), 4);
+        assert!(matches!(errors[0], Error::InvalidChar('：', pos) if pos == Position::new(1, 1)));
+        assert!(matches!(errors[1], Error::InvalidChar('，', pos) if pos == Position::new(1, 4)));
+        assert!(matches!(errors[2], Error::InvalidChar('?', _)));
+        assert!(matches!(errors[3], Error::NumberOverflow { .. }));
+    }
+
+    #[test]
+    fn configured_keywords() {
+        let input = "pRoGrAm Begin mul".as_bytes();
+        let config = LexerConfig::default()
+            .keyword_case(KeywordCase::Insensitive)
+            .keyword("mul", Token::Star);
+        let tokens: Vec<_> = Lexer::new(input)
+            .unwrap()
+            .with_config(config)
+            .map(|x| x.unwrap())
+            .collect();
+
+        assert_eq!(tokens, vec![Token::Program, Token::Begin, Token::Star]);
+    }
+
+    #[test]
+    fn config_replaces_settings() {
+        let input = "a ? 256".as_bytes();
+        let lexer = Lexer::new(input)
+            .unwrap()
+            .with_config(LexerConfig::default())
+            .with_recovery()
+            .with_max_number(255);
+        let tokens: Vec<_> = lexer.map(|x| x.unwrap()).collect();
+        assert_eq!(
+            tokens,
+            vec![Token::Id("a".into()), Token::Error, Token::Error]
+        );
+
+        let mut lexer = Lexer::new(input)
+            .unwrap()
+            .with_recovery()
+            .with_max_number(255)
+            .with_config(LexerConfig::default());
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Id("a".into())));
+        assert!(matches!(
+            lexer.next_token(),
+            Err(Error::InvalidChar('?', _))
+        ));
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Number(256)));
+    }
+
+    #[test]
+    fn compound_operators() {
+        let input = "<<=<>>>=/.{}".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input).unwrap().map(|x| x.unwrap()).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LessThan,
+                Token::LessEqual,
+                Token::NotEqual,
+                Token::GreaterThan,
+                Token::GreaterEqual,
+                Token::Slash,
+                Token::Dot,
+                Token::LeftBrace,
+                Token::RightBrace,
+            ]
+        );
+    }
+
+    #[test]
+    fn block_comments() {
+        let input = "a (* b\n*) { c } (* * ) *)*".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input).unwrap().map(|x| x.unwrap()).collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Id("a".into()),
+                Token::LeftBrace,
+                Token::Id("c".into()),
+                Token::RightBrace,
+                Token::Star,
+            ]
+        );
+
+        let config = LexerConfig::default()
+            .brace_comments(true)
+            .keep_comments(true);
+        let tokens: Vec<_> = Lexer::new(input)
+            .unwrap()
+            .with_config(config)
+            .map(|x| x.unwrap())
+            .collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Id("a".into()),
+                Token::Comment("(* b\n*)".into()),
+                Token::Comment("{ c }".into()),
+                Token::Comment("(* * ) *)".into()),
+                Token::Star,
+            ]
+        );
+    }
+
+    #[test]
+    fn unterminated_comment() {
+        let input = "a\n  (* b *".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap();
+
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Id("a".into())));
+        match lexer.next_token().unwrap_err() {
+            Error::UnterminatedComment(position) => assert_eq!(position, Position::new(2, 2)),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn numeric_literals() {
+        let input = "2.5 1e-3 2E+2 1_000 $FF 0xff_ff $A_E 0o17 0b101 0x 1. 1e".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input).unwrap().map(|x| x.unwrap()).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Real(2.5),
+                Token::Real(1e-3),
+                Token::Real(200.0),
+                Token::Number(1000),
+                Token::Number(255),
+                Token::Number(0xffff),
+                Token::Number(0xae),
+                Token::Number(15),
+                Token::Number(5),
+                Token::Number(0),
+                Token::Id("x".into()),
+                Token::Number(1),
+                Token::Dot,
+                Token::Number(1),
+                Token::Id("e".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn numeric_literal_errors() {
+        let input = "1__0 2_ $G 0x1_0000_0000 1e999 1_E5 1_e5 3".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap().with_recovery();
+        let tokens: Vec<_> = lexer.by_ref().map(|x| x.unwrap()).collect();
+
+        assert_eq!(tokens.len(), 9);
+        assert_eq!(tokens[8], Token::Number(3));
+        let errors = lexer.into_errors();
+        assert!(matches!(&errors[0], Error::MalformedNumber { text, .. } if text == "1__0"));
+        assert!(matches!(&errors[1], Error::MalformedNumber { text, .. } if text == "2_"));
+        assert!(matches!(errors[2], Error::InvalidChar('$', _)));
+        assert!(
+            matches!(&errors[3], Error::NumberOverflow { text, .. } if text == "0x1_0000_0000")
+        );
+        assert!(matches!(&errors[4], Error::NumberOverflow { text, .. } if text == "1e999"));
+        assert!(matches!(&errors[5], Error::MalformedNumber { text, .. } if text == "1_E5"));
+        assert!(matches!(&errors[6], Error::MalformedNumber { text, .. } if text == "1_e5"));
+    }
+
+    #[test]
+    fn string_literals() {
+        let input = "'it''s' 'a' '''' '' \"b\"".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input)
+            .unwrap()
+            .with_recovery()
+            .map(|x| x.unwrap())
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Str("it's".into()),
+                Token::Char('a'),
+                Token::Char('\''),
+                Token::Str("".into()),
+                Token::Error,
+                Token::Id("b".into()),
+                Token::Error,
+            ]
+        );
+    }
+
+    #[test]
+    fn c_escapes() {
+        let input = r#"'\n' "a\tb\x41\"" 'x\q' y"#.as_bytes();
+        let config = LexerConfig::default().recover(true).c_escapes(true);
+        let mut lexer = Lexer::new(input).unwrap().with_config(config);
+
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Char('\n')));
+        assert_eq!(
+            lexer.next_token().unwrap(),
+            Some(Token::Str("a\tbA\"".into()))
+        );
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Error));
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Id("y".into())));
+        match lexer.errors() {
+            [Error::InvalidEscape { escape, span }] => {
+                assert_eq!(escape, "\\q");
+                assert_eq!(span, &(Position::new(1, 20)..Position::new(1, 22)));
+            }
+            _ => panic!(),
+        }
+
+        // the quote decides between strings and characters
+        let input = r#""a" 'ab' "" 'a'"#.as_bytes();
+        let config = LexerConfig::default().recover(true).c_escapes(true);
+        let mut lexer = Lexer::new(input).unwrap().with_config(config);
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Str("a".into())));
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Error));
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Str("".into())));
+        assert_eq!(lexer.next_token().unwrap(), Some(Token::Char('a')));
+        match lexer.errors() {
+            [Error::InvalidCharLiteral { span }] => {
+                assert_eq!(span, &(Position::new(1, 4)..Position::new(1, 8)));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn unterminated_string() {
+        let input = "a := 'b\nc".as_bytes();
+        let mut lexer = Lexer::new(input).unwrap().spanned();
+
+        lexer.next().unwrap().unwrap();
+        lexer.next().unwrap().unwrap();
+        match lexer.next().unwrap().unwrap_err() {
+            Error::UnterminatedString { span } => {
+                assert_eq!(span, Position::new(1, 5)..Position::new(1, 7))
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn spanned_tokens() {
+        let input = "k:=8;\n  if k".as_bytes();
+        let tokens: Vec<_> = Lexer::new(input)
+            .unwrap()
+            .spanned()
+            .map(|x| x.unwrap())
+            .collect();
+
+        assert_eq!(tokens.len(), 6);
+        assert_eq!(
+            tokens[1],
+            SpannedToken {
+                token: Token::Assign,
+                start: Position::new(1, 1),
+                end: Position::new(1, 3),
+                byte_range: 1..3,
+            }
+        );
+        assert_eq!(
+            tokens[4],
+            SpannedToken {
+                token: Token::If,
+                start: Position::new(2, 2),
+                end: Position::new(2, 4),
+                byte_range: 8..10,
+            }
+        );
+        assert_eq!(tokens[5].byte_range, 11..12);
+    }
+
     #[test]
     fn non_ascii_is_invalid() {
         let input = "你好，世界。".as_bytes();
-        let invalid_byte = input[0];
 
         let mut lexer = Lexer::new(input).unwrap();
         let result = lexer.next().unwrap();
 
         match result.unwrap_err() {
-            Error::InvalidByte(byte, position) => {
-                assert_eq!(byte, invalid_byte);
+            Error::InvalidChar(c, position) => {
+                assert_eq!(c, '你');
                 assert_eq!(position, Position::new(1, 0));
             }
             _ => panic!(),
         }
+
+        let result = lexer.next().unwrap();
+        match result.unwrap_err() {
+            Error::InvalidChar(c, position) => {
+                assert_eq!(c, '好');
+                assert_eq!(position, Position::new(1, 1));
+            }
+            _ => panic!(),
+        }
     }
 
     #[test]
-    fn invalid_byte_position() {
+    fn invalid_char_position() {
         let input = "program\nprog\x01ram".as_bytes();
-        let invalid_byte = b'\x01';
+        let invalid_char = '\x01';
         let invalid_pos = Position::new(2, 4);
 
         let mut lexer = Lexer::new(input).unwrap();
         let result = lexer.nth(2).unwrap();
 
         match result.unwrap_err() {
-            Error::InvalidByte(byte, position) => {
-                assert_eq!(byte, invalid_byte);
+            Error::InvalidChar(c, position) => {
+                assert_eq!(c, invalid_char);
                 assert_eq!(position, invalid_pos);
             }
             _ => panic!(),
         }
     }
+
+    #[test]
+    fn invalid_utf8() {
+        let input = b"a \xe4\xbd b \xff";
+        let mut lexer = Lexer::new(&input[..]).unwrap().with_recovery();
+        let tokens: Vec<_> = lexer.by_ref().map(|x| x.unwrap()).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Id("a".into()),
+                Token::Error,
+                Token::Id("b".into()),
+                Token::Error,
+            ]
+        );
+        let errors = lexer.into_errors();
+        assert!(matches!(errors[0], Error::InvalidByte(0xe4, pos) if pos == Position::new(1, 2)));
+        assert!(matches!(errors[1], Error::InvalidByte(0xff, pos) if pos == Position::new(1, 6)));
+    }
+
+    #[test]
+    fn unicode_identifiers() {
+        let input = "变量1 := 变量1 + ä。".as_bytes();
+        let config = LexerConfig::default().unicode_identifiers(true);
+        let mut lexer = Lexer::new(input).unwrap().with_config(config).spanned();
+
+        let token = lexer.next().unwrap().unwrap();
+        assert_eq!(token.token, Token::Id("变量1".into()));
+        assert_eq!(token.end, Position::new(1, 3));
+        assert_eq!(token.byte_range, 0..7);
+
+        let tokens: Vec<_> = lexer.map(|x| x.map(|x| x.token)).collect();
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[3].as_ref().unwrap(), &Token::Id("ä".into()));
+        assert!(
+            matches!(tokens[4], Err(Error::InvalidChar('。', pos)) if pos == Position::new(1, 14))
+        );
+    }
+
+    #[test]
+    fn resume_after_io_error() {
+        let reader = FlakyReader(VecDeque::from([
+            Err(ErrorKind::WouldBlock),
+            Ok(&b"prog"[..]),
+            Err(ErrorKind::WouldBlock),
+            Ok(b"ram 12"),
+            Err(ErrorKind::Interrupted),
+            Ok(b"34 (* a"),
+            Err(ErrorKind::WouldBlock),
+            Ok(b" *) x"),
+            Err(ErrorKind::WouldBlock),
+            Ok(b";"),
+        ]));
+        let mut lexer = Lexer::new(BufReader::with_capacity(16, reader))
+            .unwrap()
+            .spanned();
+        let mut tokens = Vec::new();
+        let mut would_block = 0;
+        for token in lexer.by_ref() {
+            match token {
+                Ok(token) => tokens.push(token),
+                Err(Error::IOError(e)) if e.kind() == ErrorKind::WouldBlock => would_block += 1,
+                Err(e) => panic!("{e}"),
+            }
+        }
+
+        assert_eq!(would_block, 4);
+        assert_eq!(
+            tokens.iter().map(|x| &x.token).collect::<Vec<_>>(),
+            vec![
+                &Token::Program,
+                &Token::Number(1234),
+                &Token::Id("x".into()),
+                &Token::Semicolon,
+            ]
+        );
+        assert_eq!(tokens[2].start, Position::new(1, 21));
+        assert_eq!(tokens[2].byte_range, 21..22);
+    }
 }
@@ -0,0 +1,288 @@
+use crate::{is_utf8_continuation, Error, Position, Result, Token};
+
+/// Character-level access to the input, shared by the lexers for scanning
+/// literals.
+pub(crate) trait Cursor {
+    fn peek_char(&mut self) -> Result<Option<char>>;
+
+    fn bump_char(&mut self, c: char) -> Result<()>;
+
+    /// Returns the `n`-th byte ahead without consuming anything, the current
+    /// byte being the 0th.
+    fn peek_byte(&mut self, n: usize) -> Result<Option<u8>>;
+
+    /// Consumes the current byte, which may be part of a character.
+    fn bump_byte(&mut self) -> Result<()>;
+
+    fn position(&self) -> Position;
+
+    /// Skips what is left of the invalid input reported by `error` in
+    /// recovery mode.
+    ///
+    /// After an invalid character or byte, the rest of a non-ASCII run, e.g.
+    /// full-width punctuation, is skipped so that it is reported only once.
+    /// Nothing is skipped with `unicode_identifiers`, as the run may continue
+    /// with the start of an identifier.
+    fn skip_invalid(&mut self, error: &Error, unicode_identifiers: bool) -> Result<()> {
+        if unicode_identifiers || !matches!(error, Error::InvalidChar(..) | Error::InvalidByte(..))
+        {
+            return Ok(());
+        }
+        while self.peek_byte(0)?.is_some_and(|x| !x.is_ascii()) {
+            self.bump_byte()?;
+        }
+        Ok(())
+    }
+}
+
+/// A [`Cursor`] over a string, for scanning a literal already delimited by
+/// other means.
+pub(crate) struct StrCursor<'a> {
+    src: &'a str,
+    offset: usize,
+    position: Position,
+}
+
+impl<'a> StrCursor<'a> {
+    /// Creates a cursor over `src`, which starts at `position`.
+    pub(crate) fn new(src: &'a str, position: Position) -> Self {
+        Self {
+            src,
+            offset: 0,
+            position,
+        }
+    }
+}
+
+impl Cursor for StrCursor<'_> {
+    fn peek_char(&mut self) -> Result<Option<char>> {
+        Ok(self.src[self.offset..].chars().next())
+    }
+
+    fn bump_char(&mut self, c: char) -> Result<()> {
+        for _ in 0..c.len_utf8() {
+            self.bump_byte()?;
+        }
+        Ok(())
+    }
+
+    fn peek_byte(&mut self, n: usize) -> Result<Option<u8>> {
+        Ok(self.src.as_bytes().get(self.offset + n).copied())
+    }
+
+    fn bump_byte(&mut self) -> Result<()> {
+        match self.src.as_bytes()[self.offset] {
+            b'\n' => {
+                self.position.line += 1;
+                self.position.column = 0;
+            }
+            x if !is_utf8_continuation(x) => self.position.column += 1,
+            _ => {}
+        }
+        self.offset += 1;
+        Ok(())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+/// Scans a quoted literal starting at the opening quote.
+///
+/// By default literals are Pascal-style: single-quoted, with `''` standing
+/// for a quote. Literals of exactly one character are [`Token::Char`], others
+/// are [`Token::Str`].
+///
+/// With `c_escapes`, literals use backslash escapes instead, and the quote
+/// decides the token as in C: double-quoted literals are [`Token::Str`] and
+/// single-quoted ones are [`Token::Char`], which must contain exactly one
+/// character.
+pub(crate) fn scan_quoted(cursor: &mut impl Cursor, c_escapes: bool) -> Result<Token> {
+    let start = cursor.position();
+    let quote = cursor.peek_char()?.unwrap();
+    cursor.bump_char(quote)?;
+
+    let mut value = String::new();
+    let mut invalid_escape = None;
+    loop {
+        let c = match cursor.peek_char()? {
+            None | Some('\n' | '\r') => {
+                return Err(Error::UnterminatedString {
+                    span: start..cursor.position(),
+                })
+            }
+            Some(c) => c,
+        };
+        let escape_start = cursor.position();
+        cursor.bump_char(c)?;
+        if c == quote {
+            if !c_escapes && cursor.peek_char()? == Some(quote) {
+                cursor.bump_char(quote)?;
+                value.push(quote);
+                continue;
+            }
+            break;
+        }
+        if c != '\\' || !c_escapes {
+            value.push(c);
+            continue;
+        }
+
+        let mut escape = String::from('\\');
+        let unescaped = match cursor.peek_char()? {
+            Some(c @ ('n' | 't' | 'r' | '0' | '\\' | '\'' | '"')) => {
+                cursor.bump_char(c)?;
+                escape.push(c);
+                Some(match c {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    c => c,
+                })
+            }
+            Some('x') => {
+                cursor.bump_char('x')?;
+                escape.push('x');
+                for _ in 0..2 {
+                    match cursor.peek_char()? {
+                        Some(c) if c.is_ascii_hexdigit() => {
+                            cursor.bump_char(c)?;
+                            escape.push(c);
+                        }
+                        _ => break,
+                    }
+                }
+                u8::from_str_radix(&escape[2..], 16)
+                    .ok()
+                    .filter(|_| escape.len() == 4)
+                    .map(char::from)
+            }
+            Some(c) if c != '\n' && c != '\r' => {
+                cursor.bump_char(c)?;
+                escape.push(c);
+                None
+            }
+            _ => None,
+        };
+        match unescaped {
+            Some(c) => value.push(c),
+            // keep scanning to the closing quote, so that the rest of the
+            // literal is not lexed as tokens
+            None => {
+                invalid_escape.get_or_insert(Error::InvalidEscape {
+                    escape,
+                    span: escape_start..cursor.position(),
+                });
+            }
+        }
+    }
+
+    if let Some(e) = invalid_escape {
+        return Err(e);
+    }
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        _ if c_escapes && quote == '"' => Ok(Token::Str(value)),
+        (Some(c), None) => Ok(Token::Char(c)),
+        _ if c_escapes => Err(Error::InvalidCharLiteral {
+            span: start..cursor.position(),
+        }),
+        _ => Ok(Token::Str(value)),
+    }
+}
+
+/// Scans a number literal starting at a digit or `$`.
+///
+/// Integers may be written in decimal, or in hexadecimal with a `$` or `0x`
+/// prefix, octal with `0o` or binary with `0b`. Decimal numbers with a
+/// fraction or an exponent, e.g. `3.14` or `1e-3`, are [`Token::Real`].
+/// Digits may be separated by single underscores, e.g. `1_000`.
+pub(crate) fn scan_number(cursor: &mut impl Cursor, max_number: u32) -> Result<Token> {
+    let start = cursor.position();
+    let first = cursor.peek_byte(0)?.unwrap();
+    let (radix, prefix_len) = match (first, cursor.peek_byte(1)?) {
+        (b'$', _) => (16, 1),
+        (b'0', Some(b'x' | b'X')) => (16, 2),
+        (b'0', Some(b'o' | b'O')) => (8, 2),
+        (b'0', Some(b'b' | b'B')) => (2, 2),
+        _ => (10, 0),
+    };
+    let is_digit = |x: Option<u8>| x.is_some_and(|x| (x as char).is_digit(radix));
+    // a prefix without digits is not a prefix, e.g. `0x` is `0` followed by `x`
+    let (radix, prefix_len) = if is_digit(cursor.peek_byte(prefix_len)?) {
+        (radix, prefix_len)
+    } else if first == b'$' {
+        cursor.bump_char('$')?;
+        return Err(Error::InvalidChar('$', start));
+    } else {
+        (10, 0)
+    };
+
+    let mut text = String::new();
+    for _ in 0..prefix_len {
+        bump_byte(cursor, &mut text)?;
+    }
+    scan_digits(cursor, &mut text, radix)?;
+
+    let mut real = false;
+    if radix == 10 {
+        let is_digit = |x: Option<u8>| x.is_some_and(|x| x.is_ascii_digit());
+        // `1.` is left alone, so that `end.` style dots after numbers still work
+        if cursor.peek_byte(0)? == Some(b'.') && is_digit(cursor.peek_byte(1)?) {
+            real = true;
+            bump_byte(cursor, &mut text)?;
+            scan_digits(cursor, &mut text, 10)?;
+        }
+        if matches!(cursor.peek_byte(0)?, Some(b'e' | b'E')) {
+            let sign = matches!(cursor.peek_byte(1)?, Some(b'+' | b'-'));
+            if is_digit(cursor.peek_byte(1 + sign as usize)?) {
+                real = true;
+                for _ in 0..1 + sign as usize {
+                    bump_byte(cursor, &mut text)?;
+                }
+                scan_digits(cursor, &mut text, 10)?;
+            }
+        }
+    }
+
+    let span = start..cursor.position();
+    // a separator must sit between two digits, e.g. not before a fraction or
+    // an exponent; `_e` is fine in hexadecimal, where `e` is a digit
+    let exponent = radix == 10 && text.to_ascii_lowercase().contains("_e");
+    if text.ends_with('_') || text.contains("__") || text.contains("_.") || exponent {
+        return Err(Error::MalformedNumber { text, span });
+    }
+    let digits = text[prefix_len..].replace('_', "");
+    if real {
+        match digits.parse::<f64>() {
+            Ok(real) if real.is_finite() => Ok(Token::Real(real)),
+            _ => Err(Error::NumberOverflow { text, span }),
+        }
+    } else {
+        match u32::from_str_radix(&digits, radix) {
+            Ok(num) if num <= max_number => Ok(Token::Number(num)),
+            _ => Err(Error::NumberOverflow { text, span }),
+        }
+    }
+}
+
+/// Scans digits in `radix` and underscores, appending them to `text`.
+fn scan_digits(cursor: &mut impl Cursor, text: &mut String, radix: u32) -> Result<()> {
+    while let Some(x) = cursor.peek_byte(0)? {
+        if x != b'_' && !(x as char).is_digit(radix) {
+            break;
+        }
+        bump_byte(cursor, text)?;
+    }
+    Ok(())
+}
+
+/// Consumes the current byte, which must be ASCII, appending it to `text`.
+fn bump_byte(cursor: &mut impl Cursor, text: &mut String) -> Result<()> {
+    let c = cursor.peek_byte(0)?.unwrap() as char;
+    cursor.bump_char(c)?;
+    text.push(c);
+    Ok(())
+}
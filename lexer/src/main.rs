@@ -1,17 +1,284 @@
-use std::{env, fs::File, io::BufReader};
+use std::{collections::HashSet, env, fs::File, io::BufReader, process::exit};
 
-use anyhow::Context;
-use lexer::Lexer;
+use anyhow::{bail, Context};
+use lexer::{Lexer, LexerConfig, SpannedToken, Token, TokenDfa};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Lab,
+    Json,
+    Csv,
+    Table,
+}
+
+impl Format {
+    fn parse(s: &str) -> anyhow::Result<Self> {
+        Ok(match s {
+            "lab" => Self::Lab,
+            "json" => Self::Json,
+            "csv" => Self::Csv,
+            "table" => Self::Table,
+            _ => bail!("unknown format `{s}`, expected one of lab, json, csv, table"),
+        })
+    }
+}
+
+/// Symbol tables of distinct identifiers and constants, in order of first
+/// appearance.
+#[derive(Debug, Default)]
+struct SymbolTables {
+    identifiers: Vec<String>,
+    constants: Vec<String>,
+    seen_identifiers: HashSet<String>,
+    seen_constants: HashSet<String>,
+}
+
+impl SymbolTables {
+    fn insert(&mut self, token: &Token) {
+        match token {
+            Token::Id(id) if self.seen_identifiers.insert(id.clone()) => {
+                self.identifiers.push(id.clone());
+            }
+            Token::Number(_) | Token::Real(_) => {
+                let value = token.value().unwrap();
+                if self.seen_constants.insert(value.clone()) {
+                    self.constants.push(value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn print(&self) {
+        println!("标识符表：");
+        for (i, id) in self.identifiers.iter().enumerate() {
+            println!("{:>5}  {id}", i + 1);
+        }
+        println!("常数表：");
+        for (i, num) in self.constants.iter().enumerate() {
+            println!("{:>5}  {num}", i + 1);
+        }
+    }
+}
+
+/// Quotes a JSON string, escaping quotes, backslashes and control characters.
+fn json_string(s: &str) -> String {
+    let mut buf = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => buf.push_str("\\\""),
+            '\\' => buf.push_str("\\\\"),
+            '\n' => buf.push_str("\\n"),
+            '\r' => buf.push_str("\\r"),
+            '\t' => buf.push_str("\\t"),
+            c if c < ' ' => buf.push_str(&format!("\\u{:04x}", c as u32)),
+            c => buf.push(c),
+        }
+    }
+    buf.push('"');
+    buf
+}
+
+/// Formats a token as a JSON object, one element of the `--format json` array.
+fn json_record(token: &SpannedToken) -> String {
+    let SpannedToken {
+        token, start, end, ..
+    } = token;
+    let value = token
+        .value()
+        .map_or_else(|| "null".to_string(), |x| json_string(&x));
+    format!(
+        "{{\"serial_id\":{},\"kind\":\"{}\",\"value\":{value},\"start_line\":{},\"start_column\":{},\"end_line\":{},\"end_column\":{}}}",
+        token.serial_id(),
+        token.name(),
+        start.line,
+        start.column,
+        end.line,
+        end.column,
+    )
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn print_token(spanned: &SpannedToken, format: Format, first: bool) {
+    let SpannedToken {
+        token, start, end, ..
+    } = spanned;
+    match format {
+        Format::Lab => println!("{token}"),
+        Format::Json => print!(
+            "{}  {}",
+            if first { "" } else { ",\n" },
+            json_record(spanned)
+        ),
+        Format::Csv => println!(
+            "{},{},{},{},{},{},{}",
+            token.serial_id(),
+            token.name(),
+            csv_field(&token.value().unwrap_or_default()),
+            start.line,
+            start.column,
+            end.line,
+            end.column,
+        ),
+        Format::Table => println!(
+            "{:>5}  {:<12}  {:<16}  {:>5}:{:<5}  {:>5}:{:<5}",
+            token.serial_id(),
+            token.name(),
+            token.value().unwrap_or_default(),
+            start.line,
+            start.column,
+            end.line,
+            end.column,
+        ),
+    }
+}
 
 fn main() -> anyhow::Result<()> {
-    let mut args = env::args();
-    let path = args.nth(1).context("no input file")?;
+    let mut format = Format::Lab;
+    let mut identifiers = false;
+    let mut unicode_identifiers = false;
+    let mut dump_dfa = false;
+    let mut path = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--format" => format = Format::parse(&args.next().context("no format given")?)?,
+            "--identifiers" => identifiers = true,
+            "--unicode-identifiers" => unicode_identifiers = true,
+            "--dump-dfa" => dump_dfa = true,
+            _ if arg.starts_with("--format=") => format = Format::parse(&arg[9..])?,
+            _ if arg.starts_with("--") => bail!("unknown option `{arg}`"),
+            _ => path = Some(arg),
+        }
+    }
+
+    let config = LexerConfig::default()
+        .recover(true)
+        .unicode_identifiers(unicode_identifiers);
+    if dump_dfa {
+        // the generated lexer only knows ASCII identifiers
+        if unicode_identifiers {
+            bail!("`--dump-dfa` cannot be combined with `--unicode-identifiers`");
+        }
+        print!("{}", TokenDfa::new(config).dfa());
+        return Ok(());
+    }
+
+    let path = path.context("no input file")?;
     let file = File::open(path).context("failed to open file")?;
-    let lexer = Lexer::new(BufReader::new(file))?;
+    let mut lexer = Lexer::new(BufReader::new(file))?
+        .with_config(config)
+        .spanned();
+    let mut tables = SymbolTables::default();
+
+    match format {
+        Format::Json => println!("["),
+        Format::Csv => {
+            println!("serial_id,kind,value,start_line,start_column,end_line,end_column")
+        }
+        Format::Table => println!(
+            "{:>5}  {:<12}  {:<16}  {:<11}  {:<11}",
+            "id", "kind", "value", "start", "end"
+        ),
+        Format::Lab => {}
+    }
+    let mut first = true;
+    let mut failed = None;
+    for token in lexer.by_ref() {
+        let token = match token {
+            Ok(x) => x,
+            Err(e) => {
+                failed = Some(e);
+                break;
+            }
+        };
+        tables.insert(&token.token);
+        print_token(&token, format, first);
+        first = false;
+    }
+    // close the array even if lexing stopped early, so the output stays valid
+    if format == Format::Json {
+        if !first {
+            println!();
+        }
+        println!("]");
+    }
+    if let Some(e) = failed {
+        return Err(e.into());
+    }
+
+    if identifiers {
+        tables.print();
+    }
 
-    for token in lexer {
-        println!("{}", token?);
+    let errors = lexer.into_inner().into_errors();
+    if !errors.is_empty() {
+        for error in &errors {
+            eprintln!("error: {error}");
+        }
+        eprintln!("{} lexical error(s) found", errors.len());
+        exit(1);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use lexer::Position;
+
+    use super::*;
+
+    #[test]
+    fn json_escapes() {
+        assert_eq!(json_string("a"), r#""a""#);
+        assert_eq!(json_string(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(json_string(r"a\b"), r#""a\\b""#);
+        assert_eq!(json_string("a\nb\tc\r"), r#""a\nb\tc\r""#);
+        assert_eq!(json_string("\u{1}\u{1f}é"), r#""\u0001\u001fé""#);
+    }
+
+    #[test]
+    fn json_records() {
+        let value = "say \"hi\"\\\n";
+        let token = SpannedToken {
+            token: Token::Str(value.into()),
+            start: Position::new(1, 0),
+            end: Position::new(2, 1),
+            byte_range: 0..14,
+        };
+        let record = json_record(&token);
+        assert_eq!(
+            record,
+            r#"{"serial_id":38,"kind":"Str","value":"say \"hi\"\\\n","start_line":1,"start_column":0,"end_line":2,"end_column":1}"#
+        );
+        let json: serde_json::Value = serde_json::from_str(&record).unwrap();
+        assert_eq!(json["value"], value);
+
+        let token = SpannedToken {
+            token: Token::Semicolon,
+            ..token
+        };
+        let json: serde_json::Value = serde_json::from_str(&json_record(&token)).unwrap();
+        assert!(json["value"].is_null());
+    }
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("abc"), "abc");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), r#""a,b""#);
+        assert_eq!(csv_field(r#"say "hi""#), r#""say ""hi""""#);
+        assert_eq!(csv_field("a\nb"), "\"a\nb\"");
+        assert_eq!(csv_field("a\rb"), "\"a\rb\"");
+    }
+}
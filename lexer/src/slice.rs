@@ -0,0 +1,360 @@
+use std::iter::FusedIterator;
+
+use unicode_xid::UnicodeXID;
+
+use crate::{
+    is_utf8_continuation,
+    literal::{self, Cursor},
+    operator, Error, LexerConfig, Position, Result, SpannedToken, Symbol, SymbolTable, Token,
+};
+
+/// A token whose identifier, if any, is interned in a [`SymbolTable`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum InternedToken {
+    Id(Symbol),
+    /// Any token other than [`Token::Id`].
+    Other(Token),
+}
+
+impl InternedToken {
+    /// Converts back to a [`Token`], looking up identifiers in `symbols`.
+    pub fn to_token(&self, symbols: &SymbolTable) -> Token {
+        match self {
+            InternedToken::Id(symbol) => Token::Id(symbols.resolve(*symbol).to_string()),
+            InternedToken::Other(token) => token.clone(),
+        }
+    }
+}
+
+/// A lexer over an in-memory source.
+///
+/// Produces the same tokens as [`Lexer`](crate::Lexer), but without going
+/// through [`std::io::Read`] byte by byte and without allocating for each
+/// identifier. Identifiers are interned in a [`SymbolTable`] that can be
+/// shared with later stages.
+#[derive(Debug)]
+pub struct SliceLexer<'src, 'sym> {
+    src: &'src str,
+    offset: usize,
+    position: Position,
+    config: LexerConfig,
+    symbols: &'sym mut SymbolTable,
+    errors: Vec<Error>,
+}
+
+impl<'src, 'sym> SliceLexer<'src, 'sym> {
+    pub fn new(src: &'src str, symbols: &'sym mut SymbolTable) -> Self {
+        Self {
+            src,
+            offset: 0,
+            position: Position::default(),
+            config: LexerConfig::default(),
+            symbols,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Replaces the configuration of this lexer.
+    pub fn with_config(mut self, config: LexerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Returns the lexical errors recorded so far in recovery mode.
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    /// Consumes the lexer, returning all lexical errors recorded in recovery
+    /// mode.
+    pub fn into_errors(self) -> Vec<Error> {
+        self.errors
+    }
+
+    fn current(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.offset).copied()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.src.as_bytes().get(self.offset + 1).copied()
+    }
+
+    /// Decodes the character starting at `current`. Must only be called at a
+    /// character boundary.
+    fn current_char(&self) -> Option<char> {
+        self.src[self.offset..].chars().next()
+    }
+
+    fn advance(&mut self) {
+        match self.current() {
+            Some(b'\n') => {
+                self.position.line += 1;
+                self.position.column = 0;
+            }
+            Some(x) if !is_utf8_continuation(x) => self.position.column += 1,
+            _ => {}
+        }
+        self.offset += 1;
+    }
+
+    fn advance_char(&mut self, c: char) {
+        for _ in 0..c.len_utf8() {
+            self.advance();
+        }
+    }
+
+    fn advance_while(&mut self, f: impl Fn(u8) -> bool) {
+        while self.current().is_some_and(&f) {
+            self.advance();
+        }
+    }
+
+    fn lexeme(&self, start: usize) -> &'src str {
+        &self.src[start..self.offset]
+    }
+
+    fn at_comment(&self) -> bool {
+        match self.current() {
+            Some(b'/') => self.peek() == Some(b'/'),
+            Some(b'(') => self.peek() == Some(b'*'),
+            Some(b'{') => self.config.brace_comments,
+            _ => false,
+        }
+    }
+
+    /// Skips a comment, returning the offset of its opening delimiter.
+    fn scan_comment(&mut self) -> Result<usize> {
+        let start = self.position;
+        let start_offset = self.offset;
+        let opening = self.current().unwrap();
+        self.advance();
+        match opening {
+            b'/' => self.advance_while(|x| x != b'\n' && x != b'\r'),
+            b'(' => {
+                self.advance();
+                loop {
+                    match self.current() {
+                        None => return Err(Error::UnterminatedComment(start)),
+                        Some(b'*') if self.peek() == Some(b')') => {
+                            self.advance();
+                            self.advance();
+                            break;
+                        }
+                        Some(_) => self.advance(),
+                    }
+                }
+            }
+            _ => {
+                self.advance_while(|x| x != b'}');
+                if self.current().is_none() {
+                    return Err(Error::UnterminatedComment(start));
+                }
+                self.advance();
+            }
+        }
+        Ok(start_offset)
+    }
+
+    fn scan_token(&mut self) -> Result<InternedToken> {
+        let start = self.offset;
+        let current = self.current().unwrap();
+        let current_pos = self.position;
+        if !current.is_ascii() {
+            let c = self.current_char().unwrap();
+            self.advance_char(c);
+            return if self.config.unicode_identifiers && c.is_xid_start() {
+                Ok(self.scan_identifier(start))
+            } else {
+                Err(Error::InvalidChar(c, current_pos))
+            };
+        }
+        if current == b'\'' || current == b'"' && self.config.c_escapes {
+            let token = literal::scan_quoted(self, self.config.c_escapes)?;
+            return Ok(InternedToken::Other(token));
+        }
+        if current.is_ascii_digit() || current == b'$' {
+            let token = literal::scan_number(self, self.config.max_number)?;
+            return Ok(InternedToken::Other(token));
+        }
+        self.advance();
+        if let Some((token, len)) = operator(current, self.current()) {
+            if len == 2 {
+                self.advance();
+            }
+            return Ok(InternedToken::Other(token));
+        }
+        match current {
+            b'A'..=b'Z' | b'a'..=b'z' | b'_' => Ok(self.scan_identifier(start)),
+            x => Err(Error::InvalidChar(x as char, current_pos)),
+        }
+    }
+
+    /// Scans the rest of an identifier or keyword starting at `start`.
+    fn scan_identifier(&mut self, start: usize) -> InternedToken {
+        while let Some(x) = self.current() {
+            if x.is_ascii_alphanumeric() || x == b'_' {
+                self.advance();
+            } else if !x.is_ascii() && self.config.unicode_identifiers {
+                match self.current_char() {
+                    Some(c) if c.is_xid_continue() => self.advance_char(c),
+                    _ => break,
+                }
+            } else {
+                break;
+            }
+        }
+        let word = self.lexeme(start);
+        match self.config.lookup(word) {
+            Some(keyword) => InternedToken::Other(keyword),
+            None => InternedToken::Id(self.symbols.intern(word)),
+        }
+    }
+
+    fn next_spanned_token(&mut self) -> Result<Option<SpannedToken<InternedToken>>> {
+        loop {
+            self.advance_while(|x| x.is_ascii_whitespace());
+            if self.current().is_none() {
+                return Ok(None);
+            }
+
+            let start = self.position;
+            let start_offset = self.offset;
+            let token = if self.at_comment() {
+                match self.scan_comment() {
+                    Ok(start) if self.config.keep_comments => {
+                        let text = self.lexeme(start).to_string();
+                        InternedToken::Other(Token::Comment(text))
+                    }
+                    Ok(_) => continue,
+                    Err(e) if self.config.recover => {
+                        self.errors.push(e);
+                        InternedToken::Other(Token::Error)
+                    }
+                    Err(e) => return Err(e),
+                }
+            } else {
+                match self.scan_token() {
+                    Err(e) if self.config.recover => {
+                        self.skip_invalid(&e, self.config.unicode_identifiers)?;
+                        self.errors.push(e);
+                        InternedToken::Other(Token::Error)
+                    }
+                    x => x?,
+                }
+            };
+            return Ok(Some(SpannedToken {
+                token,
+                start,
+                end: self.position,
+                byte_range: start_offset..self.offset,
+            }));
+        }
+    }
+}
+
+impl Cursor for SliceLexer<'_, '_> {
+    fn peek_char(&mut self) -> Result<Option<char>> {
+        Ok(self.current_char())
+    }
+
+    fn bump_char(&mut self, c: char) -> Result<()> {
+        self.advance_char(c);
+        Ok(())
+    }
+
+    fn peek_byte(&mut self, n: usize) -> Result<Option<u8>> {
+        Ok(self.src.as_bytes().get(self.offset + n).copied())
+    }
+
+    fn bump_byte(&mut self) -> Result<()> {
+        self.advance();
+        Ok(())
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl Iterator for SliceLexer<'_, '_> {
+    type Item = Result<SpannedToken<InternedToken>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_spanned_token().transpose()
+    }
+}
+
+impl FusedIterator for SliceLexer<'_, '_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Lexer;
+
+    fn assert_same_tokens(input: &str, config: LexerConfig) {
+        let expected: Vec<_> = Lexer::new(input.as_bytes())
+            .unwrap()
+            .with_config(config.clone())
+            .spanned()
+            .map(|x| x.unwrap())
+            .collect();
+
+        let mut symbols = SymbolTable::new();
+        let tokens: Vec<_> = SliceLexer::new(input, &mut symbols)
+            .with_config(config)
+            .map(|x| x.unwrap())
+            .collect();
+        let tokens: Vec<_> = tokens
+            .into_iter()
+            .map(|x| SpannedToken {
+                token: x.token.to_token(&symbols),
+                start: x.start,
+                end: x.end,
+                byte_range: x.byte_range,
+            })
+            .collect();
+
+        assert_eq!(tokens, expected);
+    }
+
+    #[test]
+    fn same_as_reader() {
+        let input = "program p; (* c *) var a1, _b: integer;\n\
+                     begin a1 := (a1 + 42) * 3; { x } if a1 <> 0 then _b := a1 / 2 end. // e";
+        assert_same_tokens(input, LexerConfig::default());
+        assert_same_tokens(
+            input,
+            LexerConfig::default()
+                .brace_comments(true)
+                .keep_comments(true),
+        );
+        assert_same_tokens(
+            "a，b ? 99999999999 1.5e3 $ff 0b2 1__2 (* x",
+            LexerConfig::default().recover(true),
+        );
+        let input = "s := 'it''s'; c := '\\x\\'; d := \"x\\x4\" 'é' 'open";
+        assert_same_tokens(input, LexerConfig::default().recover(true));
+        assert_same_tokens(input, LexerConfig::default().recover(true).c_escapes(true));
+    }
+
+    #[test]
+    fn interned_identifiers() {
+        let mut symbols = SymbolTable::new();
+        let tokens: Vec<_> = SliceLexer::new("a b a begin", &mut symbols)
+            .map(|x| x.unwrap().token)
+            .collect();
+        let a = symbols.get("a").unwrap();
+        let b = symbols.get("b").unwrap();
+
+        assert_eq!(
+            tokens,
+            vec![
+                InternedToken::Id(a),
+                InternedToken::Id(b),
+                InternedToken::Id(a),
+                InternedToken::Other(Token::Begin),
+            ]
+        );
+        assert_eq!(symbols.len(), 2);
+    }
+}
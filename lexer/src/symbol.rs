@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+/// An interned identifier, valid within the [`SymbolTable`] that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+/// An interner mapping identifiers to [`Symbol`]s.
+///
+/// Symbols are numbered in order of first appearance, starting from 0.
+#[derive(Debug, Clone, Default)]
+pub struct SymbolTable {
+    names: Vec<Box<str>>,
+    symbols: HashMap<Box<str>, Symbol>,
+}
+
+impl Symbol {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the symbol of `name`, interning it if necessary.
+    pub fn intern(&mut self, name: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(name) {
+            return symbol;
+        }
+        let symbol = Symbol(self.names.len() as u32);
+        self.names.push(name.into());
+        self.symbols.insert(name.into(), symbol);
+        symbol
+    }
+
+    /// Returns the symbol of `name` if it has been interned.
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.symbols.get(name).copied()
+    }
+
+    /// Returns the name of `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `symbol` was not created by this table.
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.names[symbol.index()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Iterates over all symbols and their names in order of first appearance.
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (Symbol(i as u32), &name[..]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let mut table = SymbolTable::new();
+        let a = table.intern("a");
+        let b = table.intern("b");
+
+        assert_eq!(table.intern("a"), a);
+        assert_ne!(a, b);
+        assert_eq!(table.resolve(b), "b");
+        assert_eq!(table.get("c"), None);
+        assert_eq!(table.iter().collect::<Vec<_>>(), vec![(a, "a"), (b, "b")]);
+    }
+}
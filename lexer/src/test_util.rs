@@ -0,0 +1,21 @@
+//! Helpers shared by the randomized tests.
+
+/// A linear congruential generator, so that random tests are reproducible.
+pub(crate) struct Lcg(u32);
+
+impl Lcg {
+    pub(crate) fn new(seed: u32) -> Self {
+        Self(seed)
+    }
+
+    /// Returns a number in `0..n`.
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        self.0 = self.0.wrapping_mul(1103515245).wrapping_add(12345);
+        (self.0 >> 16) as usize % n
+    }
+
+    /// Returns one of `pieces`.
+    pub(crate) fn pick<'a>(&mut self, pieces: &[&'a str]) -> &'a str {
+        pieces[self.below(pieces.len())]
+    }
+}
@@ -1,6 +1,15 @@
-use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    io::BufRead,
+    str::FromStr,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+use thiserror::Error;
+use unicode_xid::UnicodeXID;
+
+use crate::{Error as LexerError, Result as LexerResult};
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Token {
     // Reserved words
     Program,
@@ -13,23 +22,47 @@ pub enum Token {
     Else,
     Do,
     While,
+    Not,
+    And,
+    Or,
+    Int,
+    Bool,
 
-    // Identifier & Number
+    // Identifier & Numbers
     Id(String),
     Number(u32),
+    Real(f64),
+
+    // String & Character literals
+    Str(String),
+    Char(char),
 
     // Operators & Delimiters
     Plus,
     Minus,
+    Star,
+    Slash,
     LeftParen,
     RightParen,
+    LeftBrace,
+    RightBrace,
     Equal,
+    NotEqual,
     GreaterThan,
+    GreaterEqual,
     LessThan,
+    LessEqual,
     Semicolon,
     Comma,
     Colon,
+    Dot,
     Assign,
+
+    // Comment, only produced when comments are kept
+    Comment(String),
+
+    // Placeholder for invalid input, only produced in recovery mode
+    Error,
 }
 
 impl Token {
@@ -58,6 +91,168 @@ impl Token {
             Token::Comma => 21,
             Token::Colon => 22,
             Token::Assign => 23,
+            Token::Star => 24,
+            Token::Not => 25,
+            Token::And => 26,
+            Token::Or => 27,
+            Token::Int => 28,
+            Token::Bool => 29,
+            Token::Slash => 30,
+            Token::LeftBrace => 31,
+            Token::RightBrace => 32,
+            Token::NotEqual => 33,
+            Token::GreaterEqual => 34,
+            Token::LessEqual => 35,
+            Token::Dot => 36,
+            Token::Comment(_) => 37,
+            Token::Str(_) => 38,
+            Token::Char(_) => 39,
+            Token::Real(_) => 40,
+            Token::Error => 0,
+        }
+    }
+
+    /// One token of every kind, for looking up kinds by serial id.
+    const KINDS: [Token; 41] = [
+        Token::Program,
+        Token::Begin,
+        Token::End,
+        Token::Var,
+        Token::Integer,
+        Token::If,
+        Token::Then,
+        Token::Else,
+        Token::Do,
+        Token::While,
+        Token::Not,
+        Token::And,
+        Token::Or,
+        Token::Int,
+        Token::Bool,
+        Token::Id(String::new()),
+        Token::Number(0),
+        Token::Real(0.0),
+        Token::Str(String::new()),
+        Token::Char('\0'),
+        Token::Plus,
+        Token::Minus,
+        Token::Star,
+        Token::Slash,
+        Token::LeftParen,
+        Token::RightParen,
+        Token::LeftBrace,
+        Token::RightBrace,
+        Token::Equal,
+        Token::NotEqual,
+        Token::GreaterThan,
+        Token::GreaterEqual,
+        Token::LessThan,
+        Token::LessEqual,
+        Token::Semicolon,
+        Token::Comma,
+        Token::Colon,
+        Token::Dot,
+        Token::Assign,
+        Token::Comment(String::new()),
+        Token::Error,
+    ];
+
+    /// Reconstructs a token from its serial id and the value field of its
+    /// `(serial_id,value)` representation.
+    ///
+    /// Tokens without a value expect `_`. The values of strings and
+    /// characters are escaped as written by `Display`, with `\\`, `\n` and
+    /// `\r`. Note that comments are written as `(37,_)`, so their text does
+    /// not survive a round trip.
+    pub fn from_serial(id: u16, value: &str) -> Result<Self, ParseTokenError> {
+        let invalid_value = || ParseTokenError::InvalidValue(id, value.to_string());
+        let kind = Token::KINDS
+            .into_iter()
+            .find(|x| x.serial_id() == id)
+            .ok_or(ParseTokenError::UnknownSerialId(id))?;
+        match kind {
+            Token::Id(_) if is_identifier(value) => Ok(Token::Id(value.to_string())),
+            Token::Id(_) => Err(invalid_value()),
+            Token::Number(_) => value
+                .parse()
+                .map(Token::Number)
+                .map_err(|_| invalid_value()),
+            Token::Comment(_) => Ok(Token::Comment(value.to_string())),
+            Token::Str(_) => unescape(value).map(Token::Str).ok_or_else(invalid_value),
+            Token::Char(_) => {
+                let value = unescape(value).ok_or_else(invalid_value)?;
+                let mut chars = value.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(Token::Char(c)),
+                    _ => Err(invalid_value()),
+                }
+            }
+            Token::Real(_) => match value.parse() {
+                Ok(real) if f64::is_finite(real) => Ok(Token::Real(real)),
+                _ => Err(invalid_value()),
+            },
+            _ if value == "_" => Ok(kind),
+            _ => Err(invalid_value()),
+        }
+    }
+
+    /// Returns the name of the token kind, e.g. `"Id"` for `Token::Id(_)`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Token::Program => "Program",
+            Token::Begin => "Begin",
+            Token::End => "End",
+            Token::Var => "Var",
+            Token::Integer => "Integer",
+            Token::If => "If",
+            Token::Then => "Then",
+            Token::Else => "Else",
+            Token::Do => "Do",
+            Token::While => "While",
+            Token::Not => "Not",
+            Token::And => "And",
+            Token::Or => "Or",
+            Token::Int => "Int",
+            Token::Bool => "Bool",
+            Token::Id(_) => "Id",
+            Token::Number(_) => "Number",
+            Token::Real(_) => "Real",
+            Token::Plus => "Plus",
+            Token::Minus => "Minus",
+            Token::Star => "Star",
+            Token::Slash => "Slash",
+            Token::LeftParen => "LeftParen",
+            Token::RightParen => "RightParen",
+            Token::LeftBrace => "LeftBrace",
+            Token::RightBrace => "RightBrace",
+            Token::Equal => "Equal",
+            Token::NotEqual => "NotEqual",
+            Token::GreaterThan => "GreaterThan",
+            Token::GreaterEqual => "GreaterEqual",
+            Token::LessThan => "LessThan",
+            Token::LessEqual => "LessEqual",
+            Token::Semicolon => "Semicolon",
+            Token::Comma => "Comma",
+            Token::Colon => "Colon",
+            Token::Dot => "Dot",
+            Token::Assign => "Assign",
+            Token::Comment(_) => "Comment",
+            Token::Str(_) => "Str",
+            Token::Char(_) => "Char",
+            Token::Error => "Error",
+        }
+    }
+
+    /// Returns the value carried by the token, if any.
+    pub fn value(&self) -> Option<String> {
+        match self {
+            Token::Id(id) => Some(id.clone()),
+            Token::Number(num) => Some(num.to_string()),
+            Token::Real(real) => Some(format!("{real:?}")),
+            Token::Comment(text) => Some(text.clone()),
+            Token::Str(s) => Some(s.clone()),
+            Token::Char(c) => Some(c.to_string()),
+            _ => None,
         }
     }
 }
@@ -68,8 +263,184 @@ impl Display for Token {
         match self {
             Token::Id(id) => f.write_str(id),
             Token::Number(num) => num.fmt(f),
+            Token::Real(real) => write!(f, "{real:?}"),
+            Token::Str(s) => s.chars().try_for_each(|c| write_escaped(f, c)),
+            Token::Char(c) => write_escaped(f, *c),
             _ => f.write_char('_'),
         }?;
         f.write_char(')')
     }
 }
+
+/// Writes a character of a string or character literal, escaping line breaks
+/// so that every token stays on its own line.
+fn write_escaped(f: &mut Formatter<'_>, c: char) -> FmtResult {
+    match c {
+        '\\' => f.write_str("\\\\"),
+        '\n' => f.write_str("\\n"),
+        '\r' => f.write_str("\\r"),
+        c => f.write_char(c),
+    }
+}
+
+/// Reverses [`write_escaped`], or returns `None` for an invalid escape.
+fn unescape(s: &str) -> Option<String> {
+    let mut buf = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            buf.push(c);
+            continue;
+        }
+        buf.push(match chars.next()? {
+            '\\' => '\\',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        });
+    }
+    Some(buf)
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseTokenError {
+    #[error("malformed token `{0}`, expected `(serial_id,value)`")]
+    Malformed(String),
+
+    #[error("unknown serial id {0}")]
+    UnknownSerialId(u16),
+
+    #[error("invalid value `{1}` for serial id {0}")]
+    InvalidValue(u16, String),
+}
+
+impl FromStr for Token {
+    type Err = ParseTokenError;
+
+    /// Parses a token written in the `(serial_id,value)` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || ParseTokenError::Malformed(s.to_string());
+        let (id, value) = s
+            .trim()
+            .strip_prefix('(')
+            .and_then(|x| x.strip_suffix(')'))
+            .and_then(|x| x.split_once(','))
+            .ok_or_else(malformed)?;
+        let id = id.trim().parse().map_err(|_| malformed())?;
+        Token::from_serial(id, value)
+    }
+}
+
+/// Returns whether `s` can be lexed as an identifier, with
+/// [`LexerConfig::unicode_identifiers`](crate::LexerConfig::unicode_identifiers)
+/// enabled if it is not ASCII.
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|x| x == '_' || x.is_xid_start()) && chars.all(|x| x.is_xid_continue())
+}
+
+/// Reads a token dump with one `(serial_id,value)` token per line, as written
+/// by the `lexer` binary. Blank lines are ignored.
+pub fn read_token_dump<R: BufRead>(reader: R) -> LexerResult<Vec<Token>> {
+    let mut tokens = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let token = line.parse().map_err(|source| LexerError::TokenDump {
+            line: i as u32 + 1,
+            source,
+        })?;
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_token() {
+        assert_eq!("(1,_)".parse(), Ok(Token::Program));
+        assert_eq!("(11,example)".parse(), Ok(Token::Id("example".into())));
+        assert_eq!(" (12,8) ".parse(), Ok(Token::Number(8)));
+        assert_eq!(
+            "(12,x)".parse::<Token>(),
+            Err(ParseTokenError::InvalidValue(12, "x".into()))
+        );
+        assert_eq!(
+            "(1,program)".parse::<Token>(),
+            Err(ParseTokenError::InvalidValue(1, "program".into()))
+        );
+        assert_eq!(
+            "(99,_)".parse::<Token>(),
+            Err(ParseTokenError::UnknownSerialId(99))
+        );
+        assert_eq!(
+            "(39,ab)".parse::<Token>(),
+            Err(ParseTokenError::InvalidValue(39, "ab".into()))
+        );
+        assert_eq!(
+            "1,_".parse::<Token>(),
+            Err(ParseTokenError::Malformed("1,_".into()))
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        let tokens = [
+            Token::Program,
+            Token::Id("_a1".into()),
+            Token::Number(u32::MAX),
+            Token::LessEqual,
+            Token::Dot,
+            Token::Str("it's, (x)".into()),
+            Token::Str("a\\b\nc\r".into()),
+            Token::Char('é'),
+            Token::Char('\n'),
+            Token::Real(1e-3),
+            Token::Real(100.0),
+            Token::Error,
+        ];
+        for token in tokens {
+            assert_eq!(token.to_string().parse(), Ok(token));
+        }
+        assert_eq!("(11,变量_1)".parse(), Ok(Token::Id("变量_1".into())));
+        assert_eq!(Token::Str("a\nb".into()).to_string(), r"(38,a\nb)");
+        assert_eq!(
+            "(38,a\\q)".parse::<Token>(),
+            Err(ParseTokenError::InvalidValue(38, r"a\q".into()))
+        );
+        assert!("(11,1a)".parse::<Token>().is_err());
+    }
+
+    #[test]
+    fn dump_round_trip() {
+        let tokens = vec![
+            Token::Str("line\nbreak".into()),
+            Token::Char('\r'),
+            Token::Semicolon,
+        ];
+        let dump: String = tokens.iter().map(|x| format!("{x}\n")).collect();
+        assert_eq!(dump.lines().count(), 3);
+        assert_eq!(read_token_dump(dump.as_bytes()).unwrap(), tokens);
+    }
+
+    #[test]
+    fn every_serial_id() {
+        for id in 0..Token::KINDS.len() as u16 {
+            let token = ["_", "1"]
+                .into_iter()
+                .find_map(|value| Token::from_serial(id, value).ok())
+                .unwrap();
+            assert_eq!(token.serial_id(), id);
+            assert_eq!(token.to_string().parse(), Ok(token));
+        }
+        assert_eq!(
+            Token::from_serial(Token::KINDS.len() as u16, "_"),
+            Err(ParseTokenError::UnknownSerialId(Token::KINDS.len() as u16))
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use lexer::Lexer;
+use lexer::{read_token_dump, Lexer, LexerConfig, Token};
 use std::fmt::Write;
 
 #[test]
@@ -9,3 +9,52 @@ fn it_works() {
     lexer.for_each(|x| writeln!(result, "{}", x.unwrap()).unwrap());
     assert_eq!(result, include_str!("./00_expected_result.txt"));
 }
+
+fn tokenize(program: &[u8], config: LexerConfig) -> String {
+    let lexer = Lexer::new(program).unwrap().with_config(config);
+    let mut result = String::new();
+    lexer.for_each(|x| writeln!(result, "{}", x.unwrap()).unwrap());
+    result
+}
+
+#[test]
+fn parser_dialect() {
+    let program = include_bytes!("./01_program.txt");
+    let result = tokenize(program, LexerConfig::default());
+    assert_eq!(result, include_str!("./01_expected_result.txt"));
+}
+
+#[test]
+fn compiler_dialect() {
+    let program = include_bytes!("./02_program.txt");
+    let result = tokenize(program, LexerConfig::c_like());
+    assert_eq!(result, include_str!("./02_expected_result.txt"));
+}
+
+#[test]
+fn pascal_allows_c_keywords_as_identifiers() {
+    let tokens: Vec<_> = Lexer::new(&b"var int: integer"[..])
+        .unwrap()
+        .map(|x| x.unwrap())
+        .collect();
+    assert_eq!(
+        tokens,
+        [
+            Token::Var,
+            Token::Id("int".into()),
+            Token::Colon,
+            Token::Integer
+        ]
+    );
+}
+
+#[test]
+fn read_dump() {
+    let program = include_bytes!("./00_program.txt");
+    let expected = include_bytes!("./00_expected_result.txt");
+    let tokens: Vec<_> = Lexer::new(&program[..])
+        .unwrap()
+        .map(|x| x.unwrap())
+        .collect();
+    assert_eq!(read_token_dump(&expected[..]).unwrap(), tokens);
+}
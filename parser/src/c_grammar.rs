@@ -0,0 +1,3977 @@
+// auto-generated: "lalrpop 0.20.2"
+// sha3: fa2e3bb239effe4878542c1c9f4bcf59cdbab7e201380e95560ab9e36e2184a7
+use lalrpop_util::ErrorRecovery;
+use lexer::{Position, Token};
+use ast::*;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+#[allow(unused_imports)]
+use self::__lalrpop_util::state_machine as __state_machine;
+extern crate core;
+extern crate alloc;
+
+#[rustfmt::skip]
+#[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
+mod __parse__StmtList {
+
+    use lalrpop_util::ErrorRecovery;
+    use lexer::{Position, Token};
+    use ast::*;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<>
+     {
+        Variant0(Token),
+        Variant1(char),
+        Variant2(String),
+        Variant3(u32),
+        Variant4(f64),
+        Variant5(__lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>),
+        Variant6(Position),
+        Variant7(Vec<Spanned<Stmt>>),
+        Variant8(Box<Spanned<Stmt>>),
+        Variant9(Box<Spanned<Expr>>),
+        Variant10(Vec<String>),
+        Variant11(Spanned<String>),
+        Variant12(core::option::Option<Vec<Spanned<Stmt>>>),
+        Variant13(Type),
+    }
+    const __ACTION: &[i8] = &[
+        // State 0
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 5, 36, 6, 0, 0, 7, 8, 0, 0, 0, 37, 38, 0, 0, 39,
+        // State 1
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 5, 36, 6, 0, 0, 7, 8, 0, 0, 0, 37, 38, 0, 0, 39,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0,
+        // State 3
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 4
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 5
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 6
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 7
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 5, 36, 6, 0, 0, 7, 8, 49, 0, 0, 37, 38, 0, 0, 50,
+        // State 8
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 9
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 10
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 11
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 12
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 13
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 14
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 15
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 16
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 0, 0, 0,
+        // State 17
+        4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 5, 36, 6, 0, 0, 7, 8, 62, 0, 0, 37, 38, 0, 0, 63,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, -40, -40, -40, 0, -40, 0, -40, -40, -40, -40, -40, 0, -40, 0, 0, 0, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, -10, -10, 0, -10, -27, -10, -10, -10, -10, -10, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, -8, 0, 0, 0, 0, 0, -8, 0, 0, 0, 9, 0, -8, 0, 0, 0, 0, 10, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, -11, -11, -11, 0, -11, 0, -11, -11, -11, -11, -11, 0, -11, 0, 0, 0, 0, -11, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, -15, 0, 0, 0, 0, 0, -15, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, -15, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, -20, 0, 11, 0, 12, 0, -20, 13, 14, 15, -20, 0, -20, 0, 0, 0, 0, -20, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, -24, 17, -24, 0, -24, 0, -24, -24, -24, -24, -24, 0, -24, 0, 0, 0, 0, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0,
+        // State 36
+        0, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, 0, -12, 0, 0, 0, 0, -12, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, -19, -19, -19, 0, -19, 0, -19, -19, -19, -19, -19, 0, -19, 0, 0, 0, 0, -19, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, 0, 0, -37, -37, -37, 0, 0, -37, -37, -37, 0, 0, -37, -37, 0, 0, -37,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, -14, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 19, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, -10, -10, -10, 0, -10, 0, -10, -10, -10, -10, -10, 0, -10, 0, 0, 0, 0, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, -16, 0, 0, 0, 0, 0, -16, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, -16, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, -17, 0, 0, 0, 0, 0, -17, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, -18, 0, 0, 0, 0, 0, -18, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, -25, 17, -25, 0, -25, 0, -25, -25, -25, -25, -25, 0, -25, 0, 0, 0, 0, -25, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, -26, 17, -26, 0, -26, 0, -26, -26, -26, -26, -26, 0, -26, 0, 0, 0, 0, -26, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, -22, 0, 11, 0, 12, 0, -22, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, -21, 0, 11, 0, 12, 0, -21, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, -23, 0, 11, 0, 12, 0, -23, 0, 0, 0, -23, 0, -23, 0, 0, 0, 0, -23, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, -36, -36, -36, 0, 0, -36, -36, -36, 0, 0, -36, -36, 0, 0, -36,
+        // State 59
+        0, -41, -41, -41, 0, -41, 0, -41, -41, -41, -41, -41, 0, -41, 0, 0, 0, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 60
+        0, -9, -9, -9, 0, -9, 0, -9, -9, -9, -9, -9, 0, -9, 0, 0, 0, 0, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, -13, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __action(state: i8, integer: usize) -> i8 {
+        __ACTION[(state as usize) * 30 + integer]
+    }
+    const __EOF_ACTION: &[i8] = &[
+        // State 0
+        0,
+        // State 1
+        -44,
+        // State 2
+        0,
+        // State 3
+        0,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        -37,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        -36,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        0,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+    ];
+    fn __goto(state: i8, nt: usize) -> i8 {
+        match nt {
+            2 => 22,
+            3 => match state {
+                19 => 65,
+                20 => 66,
+                21 => 68,
+                _ => 23,
+            },
+            4 => match state {
+                3 => 43,
+                4 => 45,
+                6 => 47,
+                15 => 57,
+                _ => 24,
+            },
+            5 => match state {
+                5 => 46,
+                16 => 59,
+                _ => 25,
+            },
+            6 => match state {
+                2 => 41,
+                3..=6 | 8..=16 => 44,
+                18 => 64,
+                _ => 26,
+            },
+            7 => 42,
+            8 => 27,
+            9 => 28,
+            10 => match state {
+                8 => 50,
+                9 => 51,
+                _ => 29,
+            },
+            11 => match state {
+                12 => 54,
+                13 => 55,
+                14 => 56,
+                _ => 30,
+            },
+            12 => 31,
+            13 => match state {
+                1 | 17 => 40,
+                _ => 32,
+            },
+            14 => match state {
+                7 => 17,
+                _ => 1,
+            },
+            16 => match state {
+                10 => 52,
+                11 => 53,
+                _ => 33,
+            },
+            17 => 2,
+            _ => 0,
+        }
+    }
+    const __TERMINAL: &[&str] = &[
+        r###""(""###,
+        r###"")""###,
+        r###""*""###,
+        r###""+""###,
+        r###"",""###,
+        r###""-""###,
+        r###"":=""###,
+        r###"";""###,
+        r###""<""###,
+        r###""=""###,
+        r###"">""###,
+        r###""and""###,
+        r###""bool""###,
+        r###""do""###,
+        r###""else""###,
+        r###""if""###,
+        r###""int""###,
+        r###""not""###,
+        r###""or""###,
+        r###""then""###,
+        r###""while""###,
+        r###""{""###,
+        r###""}""###,
+        r###"CHAR"###,
+        r###"ERROR"###,
+        r###"ID"###,
+        r###"NUM"###,
+        r###"REAL"###,
+        r###"STR"###,
+    ];
+    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    fn __expected_tokens_from_states<
+        'err,
+    >(
+        __states: &[i8],
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> alloc::vec::Vec<alloc::string::String>
+    {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+                Some(alloc::string::ToString::to_string(terminal))
+            } else {
+                None
+            }
+        }).collect()
+    }
+    struct __StateMachine<'err>
+    where 
+    {
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __phantom: core::marker::PhantomData<(&'err ())>,
+    }
+    impl<'err> __state_machine::ParserDefinition for __StateMachine<'err>
+    where 
+    {
+        type Location = Position;
+        type Error = lexer::Error;
+        type Token = Token;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<>;
+        type Success = Vec<Spanned<Stmt>>;
+        type StateIndex = i8;
+        type Action = i8;
+        type ReduceIndex = i8;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i8, integer: usize) -> i8 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i8) -> i8 {
+            __action(state, 30 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i8) -> i8 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i8, nt: usize) -> i8 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+        }
+
+        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        fn expected_tokens_from_states(&self, states: &[i8]) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            true
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            __Symbol::Variant5(recovery)
+        }
+
+        fn reduce(
+            &mut self,
+            action: i8,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i8>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.errors,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+        }
+    }
+    fn __token_to_integer<
+        'err,
+    >(
+        __token: &Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Token::LeftParen if true => Some(0),
+            Token::RightParen if true => Some(1),
+            Token::Star if true => Some(2),
+            Token::Plus if true => Some(3),
+            Token::Comma if true => Some(4),
+            Token::Minus if true => Some(5),
+            Token::Assign if true => Some(6),
+            Token::Semicolon if true => Some(7),
+            Token::LessThan if true => Some(8),
+            Token::Equal if true => Some(9),
+            Token::GreaterThan if true => Some(10),
+            Token::And if true => Some(11),
+            Token::Bool if true => Some(12),
+            Token::Do if true => Some(13),
+            Token::Else if true => Some(14),
+            Token::If if true => Some(15),
+            Token::Int if true => Some(16),
+            Token::Not if true => Some(17),
+            Token::Or if true => Some(18),
+            Token::Then if true => Some(19),
+            Token::While if true => Some(20),
+            Token::LeftBrace if true => Some(21),
+            Token::RightBrace if true => Some(22),
+            Token::Char(_) if true => Some(23),
+            Token::Error if true => Some(24),
+            Token::Id(_) if true => Some(25),
+            Token::Number(_) if true => Some(26),
+            Token::Real(_) if true => Some(27),
+            Token::Str(_) if true => Some(28),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'err,
+    >(
+        __token_index: usize,
+        __token: Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __Symbol<>
+    {
+        #[allow(clippy::manual_range_patterns)]match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 24 => __Symbol::Variant0(__token),
+            23 => match __token {
+                Token::Char(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            25 | 28 => match __token {
+                Token::Id(__tok0) | Token::Str(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            26 => match __token {
+                Token::Number(__tok0) if true => __Symbol::Variant3(__tok0),
+                _ => unreachable!(),
+            },
+            27 => match __token {
+                Token::Real(__tok0) if true => __Symbol::Variant4(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    fn __simulate_reduce<
+        'err,
+    >(
+        __reduce_index: i8,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'err>>
+    {
+        match __reduce_index {
+            0 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 0,
+                }
+            }
+            1 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
+            }
+            2 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 2,
+                }
+            }
+            3 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
+            }
+            4 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 2,
+                }
+            }
+            5 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 2,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 3,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 4,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 5,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 7,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 8,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 8,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 10,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 11,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 12,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 13,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 13,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 13,
+                }
+            }
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 15,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            43 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct StmtListParser {
+        _priv: (),
+    }
+
+    impl Default for StmtListParser { fn default() -> Self { Self::new() } }
+    impl StmtListParser {
+        pub fn new() -> StmtListParser {
+            StmtListParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'err,
+            __TOKEN: __ToTriple<'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<Spanned<Stmt>>, __lalrpop_util::ParseError<Position, Token, lexer::Error>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'err,
+    >(
+        __error_state: Option<i8>,
+        __states: &[i8],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.extend(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    fn __reduce<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __action: i8,
+        __lookahead_start: Option<&Position>,
+        __states: &mut alloc::vec::Vec<i8>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<Result<Vec<Spanned<Stmt>>,__lalrpop_util::ParseError<Position, Token, lexer::Error>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            43 => {
+                // __StmtList = StmtList => ActionFn(0);
+                let __sym0 = __pop_Variant7(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = super::__action0::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Expr>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Position, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, String, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Token, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Type, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Spanned<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, char, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, core::option::Option<Vec<Spanned<Stmt>>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, f64, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, u32, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __reduce0<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(42);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action42::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 0)
+    }
+    fn __reduce1<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(41);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action41::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 1)
+    }
+    fn __reduce2<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "{", StmtList, "}" => ActionFn(12);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action12::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 2)
+    }
+    fn __reduce3<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "{", "}" => ActionFn(13);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action13::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 2)
+    }
+    fn __reduce4<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "{", StmtList, error, "}" => ActionFn(85);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action85::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 2)
+    }
+    fn __reduce5<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "{", error, "}" => ActionFn(86);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action86::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 2)
+    }
+    fn __reduce6<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BlockStmt = Block => ActionFn(65);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action65::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 3)
+    }
+    fn __reduce7<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr = LogicalExpr => ActionFn(15);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 4)
+    }
+    fn __reduce8<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = "(", Expr, ")" => ActionFn(29);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action29::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 5)
+    }
+    fn __reduce9<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = Id => ActionFn(66);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action66::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce10<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = Num => ActionFn(67);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action67::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce11<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id = ID => ActionFn(36);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action36::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 6)
+    }
+    fn __reduce12<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IdList = IdList, ",", Id => ActionFn(34);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action34::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 7)
+    }
+    fn __reduce13<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IdList = Id => ActionFn(35);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action35::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 7)
+    }
+    fn __reduce14<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = RelExpr => ActionFn(16);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action16::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 8)
+    }
+    fn __reduce15<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = "not", Factor => ActionFn(68);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action68::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 8)
+    }
+    fn __reduce16<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "and", RelExpr => ActionFn(69);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action69::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 8)
+    }
+    fn __reduce17<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "or", RelExpr => ActionFn(70);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action70::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 8)
+    }
+    fn __reduce18<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Num = NUM => ActionFn(38);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action38::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 9)
+    }
+    fn __reduce19<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr => ActionFn(20);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action20::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 10)
+    }
+    fn __reduce20<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, "=", SimpleExpr => ActionFn(71);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action71::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 10)
+    }
+    fn __reduce21<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, "<", SimpleExpr => ActionFn(72);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action72::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 10)
+    }
+    fn __reduce22<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, ">", SimpleExpr => ActionFn(73);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action73::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 10)
+    }
+    fn __reduce23<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = Term => ActionFn(24);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action24::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 11)
+    }
+    fn __reduce24<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = SimpleExpr, "+", Term => ActionFn(74);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action74::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 11)
+    }
+    fn __reduce25<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = SimpleExpr, "-", Term => ActionFn(75);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action75::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 11)
+    }
+    fn __reduce26<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SpannedId = Id => ActionFn(76);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action76::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 12)
+    }
+    fn __reduce27<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = "if", Expr, "then", BlockStmt => ActionFn(77);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action77::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 13)
+    }
+    fn __reduce28<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = "if", Expr, "then", BlockStmt, "else", BlockStmt => ActionFn(78);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action78::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (6, 13)
+    }
+    fn __reduce29<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = "while", Expr, "do", BlockStmt => ActionFn(79);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action79::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 13)
+    }
+    fn __reduce30<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = SpannedId, ":=", Expr => ActionFn(80);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action80::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 13)
+    }
+    fn __reduce31<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Type, IdList => ActionFn(81);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action81::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (2, 13)
+    }
+    fn __reduce32<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = BlockStmt => ActionFn(8);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 13)
+    }
+    fn __reduce33<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Expr => ActionFn(82);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action82::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 13)
+    }
+    fn __reduce34<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = error => ActionFn(83);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action83::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 13)
+    }
+    fn __reduce35<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = StmtList, Stmt, ";" => ActionFn(1);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action1::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 14)
+    }
+    fn __reduce36<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = Stmt, ";" => ActionFn(2);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action2::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce37<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList? = StmtList => ActionFn(39);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action39::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 15)
+    }
+    fn __reduce38<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList? =  => ActionFn(40);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action40::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (0, 15)
+    }
+    fn __reduce39<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Factor => ActionFn(27);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action27::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce40<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Term, "*", Factor => ActionFn(84);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action84::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (3, 16)
+    }
+    fn __reduce41<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "int" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 17)
+    }
+    fn __reduce42<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "bool" => ActionFn(33);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 17)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__StmtList::StmtListParser;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Vec<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    {
+        let mut l = l;
+        l.push(*s);
+        l
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    vec![*s]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::If(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, o, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::IfElse(e, s, o), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::While(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, Spanned<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Assign(i, e), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, t, _): (Position, Type, Position),
+    (_, i, _): (Position, Vec<String>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::VarDecl(i, t), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    s
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Expr(e), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    {
+        errors.push(e);
+        Box::new(Spanned::new(Stmt::Error, l, r))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, b, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Block(b), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+    (_, __1, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    Vec::new()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, l, _): (Position, core::option::Option<Vec<Spanned<Stmt>>>, Position),
+    (_, el, _): (Position, Position, Position),
+    (_, e, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    (_, er, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    {
+        errors.push(e);
+        let mut l = l.unwrap_or_default();
+        l.push(Spanned::new(Stmt::Error, el, er));
+        l
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Not(f), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::And, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Or, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Equal, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::LessThan, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::GreaterThan, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    t
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Add, e, t), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Sub, e, t), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    f
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Mul, t, f), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, String, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Id(i), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, n, _): (Position, u32, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Num(n), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+) -> Type
+{
+    Type::Int
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+) -> Type
+{
+    Type::Bool
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, i, _): (Position, String, Position),
+) -> Vec<String>
+{
+    {
+        let mut l = l;
+        l.push(i);
+        l
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, i, _): (Position, String, Position),
+) -> Vec<String>
+{
+    vec![i]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, String, Position),
+) -> String
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, String, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Spanned<String>
+{
+    Spanned::new(i, l, r)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, u32, Position),
+) -> u32
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Vec<Spanned<Stmt>>, Position),
+) -> core::option::Option<Vec<Spanned<Stmt>>>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<Vec<Spanned<Stmt>>>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
+{
+    *__lookbehind
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
+{
+    *__lookahead
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action43<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, core::option::Option<Vec<Spanned<Stmt>>>, Position),
+    __2: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __3: (Position, Position, Position),
+    __4: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action44<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action45<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action46<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, u32, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action31(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action47<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action48<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action49<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action50<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action51<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action52<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action53<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action54<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action26(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action55<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+    __1: (Position, Position, Position),
+) -> Spanned<String>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action37(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action56<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action3(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action57<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+    __6: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action58<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action59<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Spanned<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action60<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Type, Position),
+    __1: (Position, Vec<String>, Position),
+    __2: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action7(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action61<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action9(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action62<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action63<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action42(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action64<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, core::option::Option<Vec<Spanned<Stmt>>>, Position),
+    __2: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __3: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action43(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action65<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action44(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action66<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action67<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, u32, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action68<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action69<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action70<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action49(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action71<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action50(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action72<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action51(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action73<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action52(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action74<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action75<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action76<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+) -> Spanned<String>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action55(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action77<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action56(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action78<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action79<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action58(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action80<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Spanned<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action59(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action81<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Type, Position),
+    __1: (Position, Vec<String>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action82<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action61(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action83<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action84<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action41(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action63(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action85<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Vec<Spanned<Stmt>>, Position),
+    __2: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __3: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    let __start0 = __1.0;
+    let __end0 = __1.2;
+    let __temp0 = __action39(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action64(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action86<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __2: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __1.0;
+    let __temp0 = __action40(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action64(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+#[allow(clippy::type_complexity, dead_code)]
+
+pub  trait __ToTriple<'err, >
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>>;
+}
+
+impl<'err, > __ToTriple<'err, > for (Position, Token, Position)
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>> {
+        Ok(value)
+    }
+}
+impl<'err, > __ToTriple<'err, > for Result<(Position, Token, Position), lexer::Error>
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>> {
+        match value {
+            Ok(v) => Ok(v),
+            Err(error) => Err(__lalrpop_util::ParseError::User { error }),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 // auto-generated: "lalrpop 0.20.2"
-// sha3: dc2f7d89c93b26aff6443b0823c14082deb5b68f395029c314aca5c9312ae56d
-use super::ast::*;
+// sha3: 1f3fa4a854ee8f23939f255ac4bec0bd82fcd5589882362848434fd08c3bc1a1
+use lalrpop_util::ErrorRecovery;
+use lexer::{Position, Token};
+use ast::*;
 #[allow(unused_extern_crates)]
 extern crate lalrpop_util as __lalrpop_util;
 #[allow(unused_imports)]
@@ -10,166 +12,232 @@ extern crate alloc;
 
 #[rustfmt::skip]
 #[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
-mod __parse__StmtList {
+mod __parse__Program {
 
-    use super::super::ast::*;
+    use lalrpop_util::ErrorRecovery;
+    use lexer::{Position, Token};
+    use ast::*;
     #[allow(unused_extern_crates)]
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(unused_imports)]
     use self::__lalrpop_util::state_machine as __state_machine;
     extern crate core;
     extern crate alloc;
-    use self::__lalrpop_util::lexer::Token;
+    use super::__ToTriple;
     #[allow(dead_code)]
-    pub(crate) enum __Symbol<'input>
+    pub(crate) enum __Symbol<>
      {
-        Variant0(&'input str),
-        Variant1(Vec<Stmt>),
-        Variant2(Box<Stmt>),
-        Variant3(Box<Expr>),
-        Variant4(String),
-        Variant5(Vec<String>),
-        Variant6(u32),
-        Variant7(Type),
+        Variant0(Token),
+        Variant1(char),
+        Variant2(String),
+        Variant3(u32),
+        Variant4(f64),
+        Variant5(__lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>),
+        Variant6(Position),
+        Variant7(Vec<Spanned<Stmt>>),
+        Variant8(Box<Spanned<Stmt>>),
+        Variant9(Vec<Spanned<VarDecl>>),
+        Variant10(Box<Spanned<Expr>>),
+        Variant11(Vec<String>),
+        Variant12(Program),
+        Variant13(Spanned<Vec<Spanned<Stmt>>>),
+        Variant14(Spanned<String>),
+        Variant15(Type),
+        Variant16(Spanned<VarDecl>),
+        Variant17(alloc::vec::Vec<Spanned<VarDecl>>),
     }
     const __ACTION: &[i8] = &[
         // State 0
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 1
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0,
         // State 2
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 6, 0, 0, 0, 57, 10, 0, 11, 0, 0, 0, 12, 13, 0, 0, 32, 58, 0, 0, 59,
         // State 6
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0,
         // State 7
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 6, 0, 0, 0, -49, 10, 0, 11, 0, 0, 0, 12, 13, 0, 0, 32, 58, 0, 0, 59,
         // State 8
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 9
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 10
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 11
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0,
         // State 12
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 13
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0,
         // State 14
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        2, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, 3, 0, 0, -35, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 16
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 17
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 20
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 21
-        2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 0, 5, 0, 0, 6, 7, 37, 38,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 58, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0, 12, 13, 0, 0, 32, 58, 0, 0, 59,
         // State 25
-        0, -37, -37, -37, 0, -37, 0, 0, -37, -37, -37, -37, -37, 0, -37, -37, -37, 0, 0, 0, -37, -37, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, -7, -7, 0, -7, 0, 8, -7, -7, -7, -7, -7, 0, 0, -7, -7, 0, 0, 0, -7, 0, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0, 12, 13, 0, 0, 32, 58, 0, 0, 59,
         // State 27
-        0, -5, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 9, 0, -5, -5, -5, 0, 0, 0, 10, -5, 0, 0, 0, 0,
+        9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0, 12, 13, 0, 0, 32, 58, 0, 0, 59,
         // State 28
-        0, -8, -8, -8, 0, -8, 0, 0, -8, -8, -8, -8, -8, 0, -8, -8, -8, 0, 0, 0, -8, -8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        0, -12, 0, 0, 0, 0, 0, 0, -12, 0, 0, 0, -12, 0, -12, -12, -12, 0, 0, 0, -12, -12, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, -21, 0, 11, 0, 12, 0, 0, -21, 13, 14, 15, -21, 0, -21, -21, -21, 0, 0, 0, -21, -21, 0, 0, 0, 0,
+        0, -15, -15, -15, -15, -15, 0, -15, -15, -15, -15, -15, -15, -15, 0, 0, -15, -15, -15, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, -15,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, -2, 0, 0, 0, 0, 0, 0, -2, -2, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, -17, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, -25, 17, -25, 0, -25, 0, 0, -25, -25, -25, -25, -25, 0, -25, -25, -25, 0, 0, 0, -25, -25, 0, 0, 0, 0,
+        0, 0, 0, 0, 14, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, -16, -16, -16, 0, -16, 0, 0, -16, -16, -16, -16, -16, 0, -16, -16, -16, 0, 0, 0, -16, -16, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0,
         // State 37
-        0, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, 0, -9, -9, -9, 0, 0, 0, -9, -9, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, -7, -7, -7, 0, -7, 0, 0, -7, -7, -7, -7, -7, 0, -7, -7, -7, 0, 0, 0, -7, -7, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
         // State 42
-        0, -13, 0, 0, 0, 0, 0, 0, -13, 0, 0, 0, -13, 0, -13, -13, -13, 0, 0, 0, -13, -13, 0, 0, 0, 0,
+        0, -52, -52, -52, 0, -52, 0, 0, 0, -52, -52, -52, -52, -52, 0, 0, -52, -52, -52, 0, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, -52,
         // State 43
-        0, 0, 0, 0, 19, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -13, -13, 0, -13, 0, 0, -45, -13, -13, -13, -13, -13, 0, 0, 0, -13, -13, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13,
         // State 44
-        0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -11, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 16, 0, 0, -11, -11, -11, 0, 0, 0, 17, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, -11,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -14, -14, -14, 0, -14, 0, 0, 0, -14, -14, -14, -14, -14, 0, 0, -14, -14, -14, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, -14,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
         // State 47
-        0, -14, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, -14, 0, -14, -14, -14, 0, 0, 0, -14, -14, 0, 0, 0, 0,
+        0, -18, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, -18, 0, 0, -18, -18, -18, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, -18,
         // State 48
-        0, -15, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, -15, 0, -15, -15, -15, 0, 0, 0, -15, -15, 0, 0, 0, 0,
+        0, -29, 0, 18, 0, 19, 0, 0, 0, -29, 20, 21, 22, -29, 0, 0, -29, -29, -29, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, -29,
         // State 49
-        0, -26, 17, -26, 0, -26, 0, 0, -26, -26, -26, -26, -26, 0, -26, -26, -26, 0, 0, 0, -26, -26, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, 0, -6, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6,
         // State 50
-        0, -27, 17, -27, 0, -27, 0, 0, -27, -27, -27, -27, -27, 0, -27, -27, -27, 0, 0, 0, -27, -27, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, -23, 0, 11, 0, 12, 0, 0, -23, 0, 0, 0, -23, 0, -23, -23, -23, 0, 0, 0, -23, -23, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
         // State 52
-        0, -22, 0, 11, 0, 12, 0, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, 0, 0, 0, -22, -22, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, -24, 0, 11, 0, 12, 0, 0, -24, 0, 0, 0, -24, 0, -24, -24, -24, 0, 0, 0, -24, -24, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -36, 24, -36, 0, -36, 0, 0, 0, -36, -36, -36, -36, -36, 0, 0, -36, -36, -36, 0, 0, 0, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, -36,
         // State 55
-        0, -38, -38, -38, 0, -38, 0, 0, -38, -38, -38, -38, -38, 0, -38, -38, -38, 0, 0, 0, -38, -38, 0, 0, 0, 0,
+        -35, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, -35, -35, 0, -35, 0, 0, 0, -35, -35, 0, 0, -35, -35, 0, 0, -35,
         // State 56
-        0, -6, -6, -6, 0, -6, 0, 0, -6, -6, -6, -6, -6, 0, -6, -6, -6, 0, 0, 0, -6, -6, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4,
         // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, -1, 0, 0, 0, 0, 0, 0, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -22, -22, -22, 0, -22, 0, 0, 0, -22, -22, -22, -22, -22, 0, 0, -22, -22, -22, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, -22,
         // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 22, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
         // State 61
-        0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, -29, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -34, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, -34, -34, 0, -34, 0, 0, 0, -34, -34, 0, 0, -34, -34, 0, 0, -34,
         // State 62
-        0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, -39, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -3, 0, 0, -3, 0, 0, 0, 0, 0, 0, 0, -3, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3,
         // State 63
-        0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -33, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, -33, -33, 0, -33, 0, 0, 0, -33, -33, 0, 0, -33, -33, 0, 0, -33,
         // State 64
-        0, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, -3, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        0, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -13, -13, -13, 0, -13, 0, 0, 0, -13, -13, -13, -13, -13, 0, 0, -13, -13, -13, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, -13,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, -19, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, -19, 0, 0, -19, -19, -19, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, -19,
+        // State 69
+        0, 0, 0, 0, 14, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, -16, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
+        // State 75
+        0, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, -20, 0, 0, -20, -20, -20, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, -20,
+        // State 76
+        0, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, -21, 0, 0, -21, -21, -21, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21,
+        // State 77
+        0, -37, 24, -37, 0, -37, 0, 0, 0, -37, -37, -37, -37, -37, 0, 0, -37, -37, -37, 0, 0, 0, -37, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, -37,
+        // State 78
+        0, -38, 24, -38, 0, -38, 0, 0, 0, -38, -38, -38, -38, -38, 0, 0, -38, -38, -38, 0, 0, 0, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, -38,
+        // State 79
+        0, -31, 0, 18, 0, 19, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, -31, 0, 0, 0, -31, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, -31,
+        // State 80
+        0, -30, 0, 18, 0, 19, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, -30, 0, 0, 0, -30, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, -30,
+        // State 81
+        0, -32, 0, 18, 0, 19, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, -32, 0, 0, 0, -32, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, -32,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, -39, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39,
+        // State 83
+        0, 0, 0, 0, 0, 0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5,
+        // State 84
+        0, -53, -53, -53, 0, -53, 0, 0, 0, -53, -53, -53, -53, -53, 0, 0, -53, -53, -53, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        // State 85
+        0, -12, -12, -12, 0, -12, 0, 0, 0, -12, -12, -12, -12, -12, 0, 0, -12, -12, -12, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, -12,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 28, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, -8, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, -7, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
     ];
     fn __action(state: i8, integer: usize) -> i8 {
-        __ACTION[(state as usize) * 26 + integer]
+        __ACTION[(state as usize) * 34 + integer]
     }
     const __EOF_ACTION: &[i8] = &[
         // State 0
@@ -203,7 +271,7 @@ mod __parse__StmtList {
         // State 14
         0,
         // State 15
-        -35,
+        0,
         // State 16
         0,
         // State 17
@@ -217,47 +285,47 @@ mod __parse__StmtList {
         // State 21
         0,
         // State 22
-        -30,
+        0,
         // State 23
-        -33,
+        0,
         // State 24
-        -31,
+        0,
         // State 25
-        -37,
+        0,
         // State 26
-        -7,
+        0,
         // State 27
-        -5,
+        0,
         // State 28
-        -8,
+        -59,
         // State 29
-        -32,
+        0,
         // State 30
-        -12,
+        0,
         // State 31
-        -21,
+        0,
         // State 32
-        -2,
+        0,
         // State 33
-        -36,
+        0,
         // State 34
-        -40,
+        0,
         // State 35
-        -25,
+        0,
         // State 36
-        -16,
+        0,
         // State 37
-        -9,
+        -27,
         // State 38
-        0,
+        -28,
         // State 39
-        -7,
+        0,
         // State 40
         0,
         // State 41
         0,
         // State 42
-        -13,
+        0,
         // State 43
         0,
         // State 44
@@ -265,112 +333,185 @@ mod __parse__StmtList {
         // State 45
         0,
         // State 46
-        -28,
+        0,
         // State 47
-        -14,
+        0,
         // State 48
-        -15,
+        0,
         // State 49
-        -26,
+        0,
         // State 50
-        -27,
+        0,
         // State 51
-        -23,
+        0,
         // State 52
-        -22,
+        0,
         // State 53
-        -24,
+        0,
         // State 54
-        -34,
+        0,
         // State 55
-        -38,
+        0,
         // State 56
-        -6,
+        0,
         // State 57
-        -1,
+        0,
         // State 58
-        -17,
+        0,
         // State 59
-        -18,
+        0,
         // State 60
         0,
         // State 61
-        -29,
+        0,
         // State 62
-        -39,
+        0,
         // State 63
-        -4,
+        0,
         // State 64
-        -20,
+        0,
         // State 65
-        -3,
+        0,
         // State 66
-        -19,
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        0,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        0,
     ];
     fn __goto(state: i8, nt: usize) -> i8 {
         match nt {
-            0 => 22,
-            1 => match state {
-                17 => 58,
-                20 => 63,
-                21 => 65,
-                _ => 23,
-            },
             2 => match state {
-                1 => 38,
-                3 => 41,
-                6 => 45,
-                7 => 46,
-                _ => 24,
+                3 => 32,
+                _ => 39,
             },
             3 => match state {
-                4 => 42,
-                16 => 55,
-                _ => 25,
-            },
-            4 => match state {
-                1 | 3..=4 | 6..=14 | 16 => 39,
-                5 | 18 => 43,
-                _ => 26,
+                24 => 87,
+                26 => 90,
+                27 => 92,
+                _ => 40,
             },
+            4 => 3,
             5 => match state {
-                18 => 60,
-                _ => 44,
+                8 => 65,
+                9 => 67,
+                12 => 70,
+                22 => 82,
+                _ => 41,
             },
-            6 => 27,
-            7 => 28,
-            8 => match state {
-                17 => 59,
-                20 => 64,
-                21 => 66,
-                _ => 29,
+            6 => match state {
+                10 => 68,
+                23 => 84,
+                _ => 42,
             },
-            9 => match state {
-                8 => 47,
-                9 => 48,
-                _ => 30,
+            7 => match state {
+                1 => 29,
+                4 | 6 | 11 => 34,
+                8..=10 | 12 | 15..=23 => 66,
+                13 => 71,
+                _ => 43,
             },
-            10 => match state {
-                12 => 51,
-                13 => 52,
-                14 => 53,
-                _ => 31,
+            8 => match state {
+                11 => 69,
+                _ => 35,
             },
-            11 => 32,
-            12 => match state {
-                15 => 54,
-                _ => 33,
+            9 => 44,
+            10 => 45,
+            11 => match state {
+                24 => 88,
+                26 => 91,
+                27 => 93,
+                _ => 46,
             },
+            12 => 28,
             13 => match state {
-                2 => 40,
-                _ => 34,
+                15 => 75,
+                16 => 76,
+                _ => 47,
             },
-            14 => match state {
-                10 => 49,
-                11 => 50,
-                _ => 35,
+            14 => 7,
+            15 => match state {
+                19 => 79,
+                20 => 80,
+                21 => 81,
+                _ => 48,
+            },
+            16 => 49,
+            17 => 33,
+            18 => match state {
+                1 => 30,
+                _ => 50,
+            },
+            19 => match state {
+                7 => 60,
+                _ => 51,
+            },
+            20 => 52,
+            21 => 53,
+            22 => match state {
+                17 => 77,
+                18 => 78,
+                _ => 54,
+            },
+            23 => match state {
+                25 => 89,
+                _ => 72,
             },
-            15 => 61,
+            24 => match state {
+                6 => 59,
+                _ => 36,
+            },
+            25 => 6,
             _ => 0,
         }
     }
@@ -381,6 +522,7 @@ mod __parse__StmtList {
         r###""+""###,
         r###"",""###,
         r###""-""###,
+        r###"".""###,
         r###"":""###,
         r###"":=""###,
         r###"";""###,
@@ -389,6 +531,7 @@ mod __parse__StmtList {
         r###"">""###,
         r###""and""###,
         r###""begin""###,
+        r###""boolean""###,
         r###""do""###,
         r###""else""###,
         r###""end""###,
@@ -396,11 +539,16 @@ mod __parse__StmtList {
         r###""integer""###,
         r###""not""###,
         r###""or""###,
+        r###""program""###,
         r###""then""###,
         r###""var""###,
         r###""while""###,
-        r###"r#"[0-9]+"#"###,
-        r###"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"###,
+        r###"CHAR"###,
+        r###"ERROR"###,
+        r###"ID"###,
+        r###"NUM"###,
+        r###"REAL"###,
+        r###"STR"###,
     ];
     fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
@@ -413,10 +561,10 @@ mod __parse__StmtList {
         }).collect()
     }
     fn __expected_tokens_from_states<
-        'input,
+        'err,
     >(
         __states: &[i8],
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> alloc::vec::Vec<alloc::string::String>
     {
         __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
@@ -427,21 +575,21 @@ mod __parse__StmtList {
             }
         }).collect()
     }
-    struct __StateMachine<'input>
+    struct __StateMachine<'err>
     where 
     {
-        input: &'input str,
-        __phantom: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __phantom: core::marker::PhantomData<(&'err ())>,
     }
-    impl<'input> __state_machine::ParserDefinition for __StateMachine<'input>
+    impl<'err> __state_machine::ParserDefinition for __StateMachine<'err>
     where 
     {
-        type Location = usize;
-        type Error = &'static str;
-        type Token = Token<'input>;
+        type Location = Position;
+        type Error = lexer::Error;
+        type Token = Token;
         type TokenIndex = usize;
-        type Symbol = __Symbol<'input>;
-        type Success = Vec<Stmt>;
+        type Symbol = __Symbol<>;
+        type Success = Program;
         type StateIndex = i8;
         type Action = i8;
         type ReduceIndex = i8;
@@ -469,7 +617,7 @@ mod __parse__StmtList {
 
         #[inline]
         fn error_action(&self, state: i8) -> i8 {
-            __action(state, 26 - 1)
+            __action(state, 34 - 1)
         }
 
         #[inline]
@@ -496,7 +644,7 @@ mod __parse__StmtList {
 
         #[inline]
         fn uses_error_recovery(&self) -> bool {
-            false
+            true
         }
 
         #[inline]
@@ -504,7 +652,7 @@ mod __parse__StmtList {
             &self,
             recovery: __state_machine::ErrorRecovery<Self>,
         ) -> Self::Symbol {
-            panic!("error recovery not enabled for this grammar")
+            __Symbol::Variant5(recovery)
         }
 
         fn reduce(
@@ -515,7 +663,7 @@ mod __parse__StmtList {
             symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
         ) -> Option<__state_machine::ParseResult<Self>> {
             __reduce(
-                self.input,
+                self.errors,
                 action,
                 start_location,
                 states,
@@ -529,124 +677,144 @@ mod __parse__StmtList {
         }
     }
     fn __token_to_integer<
-        'input,
+        'err,
     >(
-        __token: &Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
+        __token: &Token,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> Option<usize>
     {
         match *__token {
-            Token(4, _) if true => Some(0),
-            Token(5, _) if true => Some(1),
-            Token(6, _) if true => Some(2),
-            Token(7, _) if true => Some(3),
-            Token(8, _) if true => Some(4),
-            Token(9, _) if true => Some(5),
-            Token(10, _) if true => Some(6),
-            Token(11, _) if true => Some(7),
-            Token(12, _) if true => Some(8),
-            Token(13, _) if true => Some(9),
-            Token(14, _) if true => Some(10),
-            Token(15, _) if true => Some(11),
-            Token(16, _) if true => Some(12),
-            Token(17, _) if true => Some(13),
-            Token(18, _) if true => Some(14),
-            Token(19, _) if true => Some(15),
-            Token(20, _) if true => Some(16),
-            Token(21, _) if true => Some(17),
-            Token(22, _) if true => Some(18),
-            Token(23, _) if true => Some(19),
-            Token(24, _) if true => Some(20),
-            Token(25, _) if true => Some(21),
-            Token(26, _) if true => Some(22),
-            Token(27, _) if true => Some(23),
-            Token(1, _) if true => Some(24),
-            Token(2, _) if true => Some(25),
+            Token::LeftParen if true => Some(0),
+            Token::RightParen if true => Some(1),
+            Token::Star if true => Some(2),
+            Token::Plus if true => Some(3),
+            Token::Comma if true => Some(4),
+            Token::Minus if true => Some(5),
+            Token::Dot if true => Some(6),
+            Token::Colon if true => Some(7),
+            Token::Assign if true => Some(8),
+            Token::Semicolon if true => Some(9),
+            Token::LessThan if true => Some(10),
+            Token::Equal if true => Some(11),
+            Token::GreaterThan if true => Some(12),
+            Token::And if true => Some(13),
+            Token::Begin if true => Some(14),
+            Token::Bool if true => Some(15),
+            Token::Do if true => Some(16),
+            Token::Else if true => Some(17),
+            Token::End if true => Some(18),
+            Token::If if true => Some(19),
+            Token::Integer if true => Some(20),
+            Token::Not if true => Some(21),
+            Token::Or if true => Some(22),
+            Token::Program if true => Some(23),
+            Token::Then if true => Some(24),
+            Token::Var if true => Some(25),
+            Token::While if true => Some(26),
+            Token::Char(_) if true => Some(27),
+            Token::Error if true => Some(28),
+            Token::Id(_) if true => Some(29),
+            Token::Number(_) if true => Some(30),
+            Token::Real(_) if true => Some(31),
+            Token::Str(_) if true => Some(32),
             _ => None,
         }
     }
     fn __token_to_symbol<
-        'input,
+        'err,
     >(
         __token_index: usize,
-        __token: Token<'input>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __Symbol<'input>
+        __token: Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __Symbol<>
     {
         #[allow(clippy::manual_range_patterns)]match __token_index {
-            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 => match __token {
-                Token(4, __tok0) | Token(5, __tok0) | Token(6, __tok0) | Token(7, __tok0) | Token(8, __tok0) | Token(9, __tok0) | Token(10, __tok0) | Token(11, __tok0) | Token(12, __tok0) | Token(13, __tok0) | Token(14, __tok0) | Token(15, __tok0) | Token(16, __tok0) | Token(17, __tok0) | Token(18, __tok0) | Token(19, __tok0) | Token(20, __tok0) | Token(21, __tok0) | Token(22, __tok0) | Token(23, __tok0) | Token(24, __tok0) | Token(25, __tok0) | Token(26, __tok0) | Token(27, __tok0) | Token(1, __tok0) | Token(2, __tok0) if true => __Symbol::Variant0(__tok0),
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 28 => __Symbol::Variant0(__token),
+            27 => match __token {
+                Token::Char(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            29 | 32 => match __token {
+                Token::Id(__tok0) | Token::Str(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            30 => match __token {
+                Token::Number(__tok0) if true => __Symbol::Variant3(__tok0),
+                _ => unreachable!(),
+            },
+            31 => match __token {
+                Token::Real(__tok0) if true => __Symbol::Variant4(__tok0),
                 _ => unreachable!(),
             },
             _ => unreachable!(),
         }
     }
     fn __simulate_reduce<
-        'input,
+        'err,
     >(
         __reduce_index: i8,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> __state_machine::SimulatedReduce<__StateMachine<'input>>
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'err>>
     {
         match __reduce_index {
             0 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 0,
                     nonterminal_produced: 0,
                 }
             }
             1 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 0,
                     nonterminal_produced: 1,
                 }
             }
             2 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 1,
+                    states_to_pop: 3,
+                    nonterminal_produced: 2,
                 }
             }
             3 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 1,
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
                 }
             }
             4 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 4,
                     nonterminal_produced: 2,
                 }
             }
             5 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 3,
                 }
             }
             6 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 6,
                     nonterminal_produced: 3,
                 }
             }
             7 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 4,
                     nonterminal_produced: 3,
                 }
             }
             8 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 4,
                 }
             }
             9 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 5,
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
                 }
             }
             10 => {
@@ -657,203 +825,324 @@ mod __parse__StmtList {
             }
             11 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 6,
                 }
             }
             12 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 6,
                 }
             }
             13 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 1,
                     nonterminal_produced: 6,
                 }
             }
             14 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 6,
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
                 }
             }
             15 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 7,
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
                 }
             }
             16 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
+                    states_to_pop: 1,
                     nonterminal_produced: 8,
                 }
             }
             17 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 8,
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
                 }
             }
             18 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
-                    nonterminal_produced: 8,
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
                 }
             }
             19 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 8,
+                    states_to_pop: 3,
+                    nonterminal_produced: 9,
                 }
             }
             20 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 3,
                     nonterminal_produced: 9,
                 }
             }
             21 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 9,
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
                 }
             }
             22 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 9,
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
                 }
             }
             23 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 9,
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
                 }
             }
             24 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 10,
+                    states_to_pop: 6,
+                    nonterminal_produced: 11,
                 }
             }
             25 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 10,
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
                 }
             }
             26 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 10,
+                    states_to_pop: 6,
+                    nonterminal_produced: 12,
                 }
             }
             27 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 11,
+                    states_to_pop: 6,
+                    nonterminal_produced: 12,
                 }
             }
             28 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
-                    nonterminal_produced: 11,
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
                 }
             }
             29 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             30 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 11,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             31 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
                 }
             }
             32 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 12,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             33 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
-                    nonterminal_produced: 13,
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
                 }
             }
             34 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
-                    nonterminal_produced: 13,
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
                 }
             }
             35 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 13,
+                    nonterminal_produced: 15,
                 }
             }
             36 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 14,
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
                 }
             }
             37 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 3,
-                    nonterminal_produced: 14,
+                    nonterminal_produced: 15,
                 }
             }
             38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 16,
+                }
+            }
+            40 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 15,
+                    nonterminal_produced: 16,
                 }
             }
-            39 => __state_machine::SimulatedReduce::Accept,
-            _ => panic!("invalid reduction index {}", __reduce_index)
-        }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 22,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 25,
+                }
+            }
+            58 => __state_machine::SimulatedReduce::Accept,
+            59 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
     }
-    pub struct StmtListParser {
-        builder: __lalrpop_util::lexer::MatcherBuilder,
+    pub struct ProgramParser {
         _priv: (),
     }
 
-    impl Default for StmtListParser { fn default() -> Self { Self::new() } }
-    impl StmtListParser {
-        pub fn new() -> StmtListParser {
-            let __builder = super::__intern_token::new_builder();
-            StmtListParser {
-                builder: __builder,
+    impl Default for ProgramParser { fn default() -> Self { Self::new() } }
+    impl ProgramParser {
+        pub fn new() -> ProgramParser {
+            ProgramParser {
                 _priv: (),
             }
         }
 
         #[allow(dead_code)]
         pub fn parse<
-            'input,
+            'err,
+            __TOKEN: __ToTriple<'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
         >(
             &self,
-            input: &'input str,
-        ) -> Result<Vec<Stmt>, __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>
+            errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Program, __lalrpop_util::ParseError<Position, Token, lexer::Error>>
         {
-            let mut __tokens = self.builder.matcher(input);
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
             __state_machine::Parser::drive(
                 __StateMachine {
-                    input,
+                    errors,
                     __phantom: core::marker::PhantomData::<(&())>,
                 },
                 __tokens,
@@ -861,12 +1150,12 @@ mod __parse__StmtList {
         }
     }
     fn __accepts<
-        'input,
+        'err,
     >(
         __error_state: Option<i8>,
         __states: &[i8],
         __opt_integer: Option<usize>,
-        _: core::marker::PhantomData<(&'input ())>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> bool
     {
         let mut __states = __states.to_vec();
@@ -894,142 +1183,202 @@ mod __parse__StmtList {
         }
     }
     fn __reduce<
-        'input,
+        'err,
     >(
-        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
         __action: i8,
-        __lookahead_start: Option<&usize>,
+        __lookahead_start: Option<&Position>,
         __states: &mut alloc::vec::Vec<i8>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
-    ) -> Option<Result<Vec<Stmt>,__lalrpop_util::ParseError<usize, Token<'input>, &'static str>>>
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<Result<Program,__lalrpop_util::ParseError<Position, Token, lexer::Error>>>
     {
         let (__pop_states, __nonterminal) = match __action {
             0 => {
-                __reduce0(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             1 => {
-                __reduce1(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             2 => {
-                __reduce2(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             3 => {
-                __reduce3(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             4 => {
-                __reduce4(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             5 => {
-                __reduce5(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             6 => {
-                __reduce6(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             7 => {
-                __reduce7(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             8 => {
-                __reduce8(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             9 => {
-                __reduce9(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             10 => {
-                __reduce10(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             11 => {
-                __reduce11(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             12 => {
-                __reduce12(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             13 => {
-                __reduce13(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             14 => {
-                __reduce14(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             15 => {
-                __reduce15(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             16 => {
-                __reduce16(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             17 => {
-                __reduce17(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             18 => {
-                __reduce18(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             19 => {
-                __reduce19(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             20 => {
-                __reduce20(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             21 => {
-                __reduce21(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             22 => {
-                __reduce22(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             23 => {
-                __reduce23(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             24 => {
-                __reduce24(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             25 => {
-                __reduce25(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             26 => {
-                __reduce26(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             27 => {
-                __reduce27(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             28 => {
-                __reduce28(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             29 => {
-                __reduce29(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             30 => {
-                __reduce30(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             31 => {
-                __reduce31(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             32 => {
-                __reduce32(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             33 => {
-                __reduce33(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             34 => {
-                __reduce34(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             35 => {
-                __reduce35(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             36 => {
-                __reduce36(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             37 => {
-                __reduce37(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             38 => {
-                __reduce38(input, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
             }
             39 => {
-                // __StmtList = StmtList => ActionFn(0);
-                let __sym0 = __pop_Variant1(__symbols);
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            57 => {
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            58 => {
+                // __Program = Program => ActionFn(0);
+                let __sym0 = __pop_Variant12(__symbols);
                 let __start = __sym0.0;
                 let __end = __sym0.2;
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action0::<>(errors, __sym0);
                 return Some(Ok(__nt));
             }
+            59 => {
+                __reduce59(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
             _ => panic!("invalid action code {}", __action)
         };
         let __states_len = __states.len();
@@ -1043,1437 +1392,6312 @@ mod __parse__StmtList {
     fn __symbol_type_mismatch() -> ! {
         panic!("symbol type mismatch")
     }
-    fn __pop_Variant3<
-      'input,
+    fn __pop_Variant10<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Expr>>, Position)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Position, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Program, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<VarDecl>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<Vec<Spanned<Stmt>>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant2<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Stmt>, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, String, Position)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant4<
-      'input,
+    fn __pop_Variant0<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Token, Position)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Type, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant7<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Type, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Spanned<Stmt>>, Position)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant1<
-      'input,
+    fn __pop_Variant9<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<Stmt>, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Spanned<VarDecl>>, Position)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __pop_Variant5<
-      'input,
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Vec<String>, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position)
      {
         match __symbols.pop() {
             Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant6<
-      'input,
+    fn __pop_Variant17<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, u32, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<Spanned<VarDecl>>, Position)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
-    fn __pop_Variant0<
-      'input,
+    fn __pop_Variant1<
     >(
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize)
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, char, Position)
      {
         match __symbols.pop() {
-            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, f64, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, u32, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
             _ => __symbol_type_mismatch()
         }
     }
     fn __reduce0<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Block = "begin", StmtList, "end" => ActionFn(17);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 0)
+        // @L =  => ActionFn(57);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action57::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 0)
     }
     fn __reduce1<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // ClosedStmt = SimpleStmt => ActionFn(10);
-        let __sym0 = __pop_Variant2(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action10::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 1)
+        // @R =  => ActionFn(56);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action56::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 1)
     }
     fn __reduce2<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // ClosedStmt = "if", Expr, "then", ClosedStmt, "else", ClosedStmt => ActionFn(11);
-        assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant2(__symbols);
-        let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
+        // Block = "begin", StmtList, "end" => ActionFn(28);
+        assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym5.2;
-        let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (6, 1)
+        let __end = __sym2.2;
+        let __nt = super::__action28::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 2)
     }
     fn __reduce3<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // ClosedStmt = "while", Expr, "do", ClosedStmt => ActionFn(12);
-        assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant2(__symbols);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        // Block = "begin", "end" => ActionFn(29);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym3.2;
-        let __nt = super::__action12::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (4, 1)
+        let __end = __sym1.2;
+        let __nt = super::__action29::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 2)
     }
     fn __reduce4<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Expr = LogicalExpr => ActionFn(18);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Block = "begin", Stmts, error, "end" => ActionFn(86);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action18::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 2)
+        let __end = __sym3.2;
+        let __nt = super::__action86::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 2)
     }
     fn __reduce5<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Factor = "(", Expr, ")" => ActionFn(32);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // ClosedStmt = SimpleStmt => ActionFn(20);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 3)
+        let __end = __sym0.2;
+        let __nt = super::__action20::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 3)
     }
     fn __reduce6<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Factor = Id => ActionFn(33);
-        let __sym0 = __pop_Variant4(__symbols);
+        // ClosedStmt = "if", Expr, "then", ClosedStmt, "else", ClosedStmt => ActionFn(87);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action33::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 3)
+        let __end = __sym5.2;
+        let __nt = super::__action87::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (6, 3)
     }
     fn __reduce7<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Factor = Num => ActionFn(34);
-        let __sym0 = __pop_Variant6(__symbols);
+        // ClosedStmt = "while", Expr, "do", ClosedStmt => ActionFn(88);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action34::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 3)
+        let __end = __sym3.2;
+        let __nt = super::__action88::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 3)
     }
     fn __reduce8<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Id = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(38);
+        // Decls = "var", VarDecl+ => ActionFn(4);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant17(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action38::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
-        (1, 4)
+        let __end = __sym1.2;
+        let __nt = super::__action4::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 4)
     }
     fn __reduce9<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // IdList = Id, ",", IdList => ActionFn(36);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant5(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
-        let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
-        (3, 5)
+        // Decls =  => ActionFn(5);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action5::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 4)
     }
     fn __reduce10<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // IdList = Id => ActionFn(37);
-        let __sym0 = __pop_Variant4(__symbols);
+        // Expr = LogicalExpr => ActionFn(32);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action37::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 5)
     }
     fn __reduce11<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // LogicalExpr = RelExpr => ActionFn(19);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Factor = "(", Expr, ")" => ActionFn(46);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
-        let __end = __sym0.2;
-        let __nt = super::__action19::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 6)
+        let __end = __sym2.2;
+        let __nt = super::__action46::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 6)
     }
     fn __reduce12<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // LogicalExpr = "not", Factor => ActionFn(20);
-        assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Factor = Id => ActionFn(89);
+        let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
-        let __end = __sym1.2;
-        let __nt = super::__action20::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (2, 6)
+        let __end = __sym0.2;
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 6)
     }
     fn __reduce13<
-        'input,
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // LogicalExpr = LogicalExpr, "and", RelExpr => ActionFn(21);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Factor = Num => ActionFn(90);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action21::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 6)
+        let __end = __sym0.2;
+        let __nt = super::__action90::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 6)
     }
     fn __reduce14<
-        'input,
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id = ID => ActionFn(53);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 7)
+    }
+    fn __reduce15<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // LogicalExpr = LogicalExpr, "or", RelExpr => ActionFn(22);
+        // IdList = IdList, ",", Id => ActionFn(51);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 6)
+        let __nt = super::__action51::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 8)
     }
-    fn __reduce15<
-        'input,
+    fn __reduce16<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IdList = Id => ActionFn(52);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 8)
+    }
+    fn __reduce17<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = RelExpr => ActionFn(33);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 9)
+    }
+    fn __reduce18<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Num = r#"[0-9]+"# => ActionFn(39);
+        // LogicalExpr = "not", Factor => ActionFn(91);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action91::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 9)
+    }
+    fn __reduce19<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "and", RelExpr => ActionFn(92);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action92::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 9)
+    }
+    fn __reduce20<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "or", RelExpr => ActionFn(93);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action93::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 9)
+    }
+    fn __reduce21<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Num = NUM => ActionFn(55);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
-        (1, 7)
+        let __nt = super::__action55::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 10)
     }
-    fn __reduce16<
-        'input,
+    fn __reduce22<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // OpenStmt = "if", Expr, "then", ClosedStmt => ActionFn(6);
+        // OpenStmt = "if", Expr, "then", ClosedStmt => ActionFn(94);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action6::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (4, 8)
+        let __nt = super::__action94::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
     }
-    fn __reduce17<
-        'input,
+    fn __reduce23<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // OpenStmt = "if", Expr, "then", OpenStmt => ActionFn(7);
+        // OpenStmt = "if", Expr, "then", OpenStmt => ActionFn(95);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (4, 8)
+        let __nt = super::__action95::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
     }
-    fn __reduce18<
-        'input,
+    fn __reduce24<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // OpenStmt = "if", Expr, "then", ClosedStmt, "else", OpenStmt => ActionFn(8);
+        // OpenStmt = "if", Expr, "then", ClosedStmt, "else", OpenStmt => ActionFn(96);
         assert!(__symbols.len() >= 6);
-        let __sym5 = __pop_Variant2(__symbols);
+        let __sym5 = __pop_Variant8(__symbols);
         let __sym4 = __pop_Variant0(__symbols);
-        let __sym3 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (6, 8)
+        let __nt = super::__action96::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (6, 11)
     }
-    fn __reduce19<
-        'input,
+    fn __reduce25<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // OpenStmt = "while", Expr, "do", OpenStmt => ActionFn(9);
+        // OpenStmt = "while", Expr, "do", OpenStmt => ActionFn(97);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant2(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant3(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action9::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (4, 8)
+        let __nt = super::__action97::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
     }
-    fn __reduce20<
-        'input,
+    fn __reduce26<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // RelExpr = SimpleExpr => ActionFn(23);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Program = "program", SpannedId, ";", Decls, SpannedBlock, "." => ActionFn(2);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action2::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 12)
+    }
+    fn __reduce27<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Program = "program", SpannedId, ";", Decls, SpannedBlock, error => ActionFn(3);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action3::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 12)
+    }
+    fn __reduce28<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr => ActionFn(37);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action23::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 9)
+        let __nt = super::__action37::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 13)
     }
-    fn __reduce21<
-        'input,
+    fn __reduce29<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // RelExpr = SimpleExpr, "=", SimpleExpr => ActionFn(24);
+        // RelExpr = SimpleExpr, "=", SimpleExpr => ActionFn(98);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action24::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 9)
+        let __nt = super::__action98::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
     }
-    fn __reduce22<
-        'input,
+    fn __reduce30<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // RelExpr = SimpleExpr, "<", SimpleExpr => ActionFn(25);
+        // RelExpr = SimpleExpr, "<", SimpleExpr => ActionFn(99);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action25::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 9)
+        let __nt = super::__action99::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
     }
-    fn __reduce23<
-        'input,
+    fn __reduce31<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // RelExpr = SimpleExpr, ">", SimpleExpr => ActionFn(26);
+        // RelExpr = SimpleExpr, ">", SimpleExpr => ActionFn(100);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action26::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 9)
+        let __nt = super::__action100::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
     }
-    fn __reduce24<
-        'input,
+    fn __reduce32<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleExpr = Term => ActionFn(27);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Semis = Stmts, ";" => ActionFn(11);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action11::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce33<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Semis = Semis, ";" => ActionFn(12);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action12::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce34<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Semis = ";" => ActionFn(13);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 10)
+        let __nt = super::__action13::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 14)
     }
-    fn __reduce25<
-        'input,
+    fn __reduce35<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = Term => ActionFn(41);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action41::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 15)
+    }
+    fn __reduce36<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleExpr = SimpleExpr, "+", Term => ActionFn(28);
+        // SimpleExpr = SimpleExpr, "+", Term => ActionFn(101);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action28::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 10)
+        let __nt = super::__action101::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 15)
     }
-    fn __reduce26<
-        'input,
+    fn __reduce37<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleExpr = SimpleExpr, "-", Term => ActionFn(29);
+        // SimpleExpr = SimpleExpr, "-", Term => ActionFn(102);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action29::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 10)
+        let __nt = super::__action102::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 15)
     }
-    fn __reduce27<
-        'input,
+    fn __reduce38<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleStmt = Id, ":=", Expr => ActionFn(13);
+        // SimpleStmt = SpannedId, ":=", Expr => ActionFn(103);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (3, 11)
+        let __nt = super::__action103::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 16)
     }
-    fn __reduce28<
-        'input,
+    fn __reduce39<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleStmt = "var", IdList, ":", Type => ActionFn(14);
+        // SimpleStmt = "var", IdList, ":", Type => ActionFn(104);
         assert!(__symbols.len() >= 4);
-        let __sym3 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant15(__symbols);
         let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action14::<>(input, __sym0, __sym1, __sym2, __sym3);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (4, 11)
+        let __nt = super::__action104::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 16)
     }
-    fn __reduce29<
-        'input,
+    fn __reduce40<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleStmt = Block => ActionFn(15);
-        let __sym0 = __pop_Variant1(__symbols);
+        // SimpleStmt = Block => ActionFn(105);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action15::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 11)
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
     }
-    fn __reduce30<
-        'input,
+    fn __reduce41<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // SimpleStmt = Expr => ActionFn(16);
-        let __sym0 = __pop_Variant3(__symbols);
+        // SimpleStmt = Expr => ActionFn(106);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action16::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 11)
+        let __nt = super::__action106::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
     }
-    fn __reduce31<
-        'input,
+    fn __reduce42<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = OpenStmt => ActionFn(4);
-        let __sym0 = __pop_Variant2(__symbols);
+        // SimpleStmt = error => ActionFn(107);
+        let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action4::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 12)
+        let __nt = super::__action107::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
     }
-    fn __reduce32<
-        'input,
+    fn __reduce43<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SpannedBlock = Block => ActionFn(108);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action108::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 17)
+    }
+    fn __reduce44<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Stmt = ClosedStmt => ActionFn(5);
+        // SpannedId = Id => ActionFn(109);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action5::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
-        (1, 12)
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 18)
     }
-    fn __reduce33<
-        'input,
+    fn __reduce45<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // StmtList = StmtList, ";", Stmt => ActionFn(1);
-        assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        // Stmt = OpenStmt => ActionFn(14);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
-        let __end = __sym2.2;
-        let __nt = super::__action1::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (3, 13)
+        let __end = __sym0.2;
+        let __nt = super::__action14::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 19)
     }
-    fn __reduce34<
-        'input,
+    fn __reduce46<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = ClosedStmt => ActionFn(15);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 19)
+    }
+    fn __reduce47<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = Stmts => ActionFn(7);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce48<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = Semis => ActionFn(8);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce49<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // StmtList = StmtList, ";" => ActionFn(2);
+        // Stmts = Semis, Stmt => ActionFn(9);
         assert!(__symbols.len() >= 2);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action2::<>(input, __sym0, __sym1);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (2, 13)
+        let __nt = super::__action9::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 21)
     }
-    fn __reduce35<
-        'input,
+    fn __reduce50<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // StmtList = Stmt => ActionFn(3);
-        let __sym0 = __pop_Variant2(__symbols);
+        // Stmts = Stmt => ActionFn(10);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action3::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
-        (1, 13)
+        let __nt = super::__action10::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 21)
     }
-    fn __reduce36<
-        'input,
+    fn __reduce51<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Term = Factor => ActionFn(30);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Term = Factor => ActionFn(44);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (1, 14)
+        let __nt = super::__action44::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 22)
     }
-    fn __reduce37<
-        'input,
+    fn __reduce52<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Term = Term, "*", Factor => ActionFn(31);
+        // Term = Term, "*", Factor => ActionFn(110);
         assert!(__symbols.len() >= 3);
-        let __sym2 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant10(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
-        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
-        (3, 14)
+        let __nt = super::__action110::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 22)
     }
-    fn __reduce38<
-        'input,
+    fn __reduce53<
+        'err,
     >(
-        input: &'input str,
-        __lookahead_start: Option<&usize>,
-        __symbols: &mut alloc::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: core::marker::PhantomData<(&'input ())>,
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
     ) -> (usize, usize)
     {
-        // Type = "integer" => ActionFn(35);
+        // Type = "integer" => ActionFn(49);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action35::<>(input, __sym0);
-        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
-        (1, 15)
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
     }
-}
-#[allow(unused_imports)]
-pub use self::__parse__StmtList::StmtListParser;
-#[rustfmt::skip]
-mod __intern_token {
-    #![allow(unused_imports)]
-    use super::super::ast::*;
-    #[allow(unused_extern_crates)]
-    extern crate lalrpop_util as __lalrpop_util;
-    #[allow(unused_imports)]
-    use self::__lalrpop_util::state_machine as __state_machine;
-    extern crate core;
-    extern crate alloc;
-    pub fn new_builder() -> __lalrpop_util::lexer::MatcherBuilder {
-        let __strs: &[(&str, bool)] = &[
-            ("(?:(?://)[\0-\t\u{b}\u{c}\u{e}-\u{10ffff}]*[\n\r]*)", true),
-            ("[0-9]+", false),
-            ("(?:[A-Z_a-z][0-9A-Z_a-z]*)", false),
-            ("[\t-\r \u{85}\u{a0}\u{1680}\u{2000}-\u{200a}\u{2028}\u{2029}\u{202f}\u{205f}\u{3000}]*", true),
-            ("\\(", false),
-            ("\\)", false),
-            ("\\*", false),
-            ("\\+", false),
-            (",", false),
-            ("\\-", false),
-            (":", false),
-            ("(?::=)", false),
-            (";", false),
-            ("<", false),
-            ("=", false),
-            (">", false),
-            ("(?:and)", false),
-            ("(?:begin)", false),
-            ("(?:do)", false),
-            ("(?:else)", false),
-            ("(?:end)", false),
-            ("(?:if)", false),
-            ("(?:integer)", false),
-            ("(?:not)", false),
-            ("(?:or)", false),
-            ("(?:then)", false),
-            ("(?:var)", false),
-            ("(?:while)", false),
-        ];
-        __lalrpop_util::lexer::MatcherBuilder::new(__strs.iter().copied()).unwrap()
-    }
-}
-pub(crate) use self::__lalrpop_util::lexer::Token;
+    fn __reduce54<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "boolean" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
+    }
+    fn __reduce55<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl = IdList, ":", Type, ";" => ActionFn(111);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action111::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (4, 24)
+    }
+    fn __reduce56<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl+ = VarDecl => ActionFn(58);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action58::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 25)
+    }
+    fn __reduce57<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl+ = VarDecl+, VarDecl => ActionFn(59);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action59::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 25)
+    }
+    fn __reduce59<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // __StmtList = StmtList => ActionFn(1);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action1::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__Program::ProgramParser;
+
+#[rustfmt::skip]
+#[allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::needless_lifetimes, clippy::type_complexity, clippy::needless_return, clippy::too_many_arguments, clippy::never_loop, clippy::match_single_binding, clippy::needless_raw_string_hashes)]
+mod __parse__StmtList {
+
+    use lalrpop_util::ErrorRecovery;
+    use lexer::{Position, Token};
+    use ast::*;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<>
+     {
+        Variant0(Token),
+        Variant1(char),
+        Variant2(String),
+        Variant3(u32),
+        Variant4(f64),
+        Variant5(__lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>),
+        Variant6(Position),
+        Variant7(Vec<Spanned<Stmt>>),
+        Variant8(Box<Spanned<Stmt>>),
+        Variant9(Vec<Spanned<VarDecl>>),
+        Variant10(Box<Spanned<Expr>>),
+        Variant11(Vec<String>),
+        Variant12(Program),
+        Variant13(Spanned<Vec<Spanned<Stmt>>>),
+        Variant14(Spanned<String>),
+        Variant15(Type),
+        Variant16(Spanned<VarDecl>),
+        Variant17(alloc::vec::Vec<Spanned<VarDecl>>),
+    }
+    const __ACTION: &[i8] = &[
+        // State 0
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 4, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 1
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 4, 0, 0, 0, -49, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 2
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 3
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 4, 0, 0, 0, 50, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 4
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 5
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
+        // State 7
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 8
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 9
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 10
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 11
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 12
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 13
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 14
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 15
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 16
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0,
+        // State 17
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 21
+        3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 5, 0, 6, 0, 0, 0, 7, 8, 0, 0, 40, 41, 0, 0, 42,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, -42, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42,
+        // State 25
+        0, -52, -52, -52, 0, -52, 0, 0, 0, -52, -52, -52, -52, -52, 0, 0, -52, -52, -52, 0, 0, 0, -52, 0, -52, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        // State 26
+        0, 0, -13, -13, 0, -13, 0, 0, -45, -13, -13, -13, -13, -13, 0, 0, 0, -13, -13, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13,
+        // State 27
+        0, -11, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 9, 0, 0, -11, -11, -11, 0, 0, 0, 10, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, -11,
+        // State 28
+        0, -14, -14, -14, 0, -14, 0, 0, 0, -14, -14, -14, -14, -14, 0, 0, -14, -14, -14, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, -14,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46,
+        // State 30
+        0, -18, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, -18, 0, 0, -18, -18, -18, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, -18,
+        // State 31
+        0, -29, 0, 11, 0, 12, 0, 0, 0, -29, 13, 14, 15, -29, 0, 0, -29, -29, -29, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, -29,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -6, 0, 0, 0, 0, 0, 0, 0, -6, -6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -6,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, -36, 17, -36, 0, -36, 0, 0, 0, -36, -36, -36, -36, -36, 0, 0, -36, -36, -36, 0, 0, 0, -36, 0, -36, 0, 0, 0, 0, 0, 0, 0, 0, -36,
+        // State 38
+        -35, 0, 0, 0, 0, 0, 0, 0, 0, -35, 0, 0, 0, 0, -35, 0, 0, 0, -35, -35, 0, -35, 0, 0, 0, -35, -35, 0, 0, -35, -35, 0, 0, -35,
+        // State 39
+        0, -15, -15, -15, -15, -15, 0, -15, -15, -15, -15, -15, -15, -15, 0, 0, -15, -15, -15, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, -15,
+        // State 40
+        0, -22, -22, -22, 0, -22, 0, 0, 0, -22, -22, -22, -22, -22, 0, 0, -22, -22, -22, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, -22,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, -43, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
+        // State 43
+        -34, 0, 0, 0, 0, 0, 0, 0, 0, -34, 0, 0, 0, 0, -34, 0, 0, 0, -34, -34, 0, -34, 0, 0, 0, -34, -34, 0, 0, -34, -34, 0, 0, -34,
+        // State 44
+        -33, 0, 0, 0, 0, 0, 0, 0, 0, -33, 0, 0, 0, 0, -33, 0, 0, 0, -33, -33, 0, -33, 0, 0, 0, -33, -33, 0, 0, -33, -33, 0, 0, -33,
+        // State 45
+        0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, -13, -13, -13, 0, -13, 0, 0, 0, -13, -13, -13, -13, -13, 0, 0, -13, -13, -13, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, -13,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -4,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, -19, 0, 0, 0, 0, 0, 0, 0, -19, 0, 0, 0, -19, 0, 0, -19, -19, -19, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, -19,
+        // State 52
+        0, 0, 0, 0, -17, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 19, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, -20, 0, 0, 0, 0, 0, 0, 0, -20, 0, 0, 0, -20, 0, 0, -20, -20, -20, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, -20,
+        // State 56
+        0, -21, 0, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, -21, 0, 0, -21, -21, -21, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, -21,
+        // State 57
+        0, -37, 17, -37, 0, -37, 0, 0, 0, -37, -37, -37, -37, -37, 0, 0, -37, -37, -37, 0, 0, 0, -37, 0, -37, 0, 0, 0, 0, 0, 0, 0, 0, -37,
+        // State 58
+        0, -38, 17, -38, 0, -38, 0, 0, 0, -38, -38, -38, -38, -38, 0, 0, -38, -38, -38, 0, 0, 0, -38, 0, -38, 0, 0, 0, 0, 0, 0, 0, 0, -38,
+        // State 59
+        0, -31, 0, 11, 0, 12, 0, 0, 0, -31, 0, 0, 0, -31, 0, 0, -31, -31, -31, 0, 0, 0, -31, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, -31,
+        // State 60
+        0, -30, 0, 11, 0, 12, 0, 0, 0, -30, 0, 0, 0, -30, 0, 0, -30, -30, -30, 0, 0, 0, -30, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, -30,
+        // State 61
+        0, -32, 0, 11, 0, 12, 0, 0, 0, -32, 0, 0, 0, -32, 0, 0, -32, -32, -32, 0, 0, 0, -32, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, -32,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, -39, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -39,
+        // State 63
+        0, -53, -53, -53, 0, -53, 0, 0, 0, -53, -53, -53, -53, -53, 0, 0, -53, -53, -53, 0, 0, 0, -53, 0, -53, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        // State 64
+        0, -12, -12, -12, 0, -12, 0, 0, 0, -12, -12, -12, -12, -12, 0, 0, -12, -12, -12, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, -12,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -3, 0, 0, 0, 0, 0, 0, 0, -3, -3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -3,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -5,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, 22, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24,
+        // State 70
+        0, 0, 0, 0, -16, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, -8, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -8,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -7, 0, 0, 0, 0, 0, 0, 0, -7, -7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -7,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
+    ];
+    fn __action(state: i8, integer: usize) -> i8 {
+        __ACTION[(state as usize) * 34 + integer]
+    }
+    const __EOF_ACTION: &[i8] = &[
+        // State 0
+        0,
+        // State 1
+        -49,
+        // State 2
+        0,
+        // State 3
+        0,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        -41,
+        // State 23
+        -47,
+        // State 24
+        -42,
+        // State 25
+        -52,
+        // State 26
+        -13,
+        // State 27
+        -11,
+        // State 28
+        -14,
+        // State 29
+        -46,
+        // State 30
+        -18,
+        // State 31
+        -29,
+        // State 32
+        -6,
+        // State 33
+        0,
+        // State 34
+        -51,
+        // State 35
+        -60,
+        // State 36
+        -48,
+        // State 37
+        -36,
+        // State 38
+        -35,
+        // State 39
+        -15,
+        // State 40
+        -22,
+        // State 41
+        -43,
+        // State 42
+        -50,
+        // State 43
+        -34,
+        // State 44
+        -33,
+        // State 45
+        0,
+        // State 46
+        -13,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        -4,
+        // State 50
+        0,
+        // State 51
+        -19,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        -20,
+        // State 56
+        -21,
+        // State 57
+        -37,
+        // State 58
+        -38,
+        // State 59
+        -31,
+        // State 60
+        -30,
+        // State 61
+        -32,
+        // State 62
+        -39,
+        // State 63
+        -53,
+        // State 64
+        -12,
+        // State 65
+        -3,
+        // State 66
+        0,
+        // State 67
+        -5,
+        // State 68
+        -23,
+        // State 69
+        -24,
+        // State 70
+        0,
+        // State 71
+        -40,
+        // State 72
+        -55,
+        // State 73
+        -54,
+        // State 74
+        -8,
+        // State 75
+        -26,
+        // State 76
+        -7,
+        // State 77
+        -25,
+    ];
+    fn __goto(state: i8, nt: usize) -> i8 {
+        match nt {
+            2 => 22,
+            3 => match state {
+                17 => 68,
+                20 => 74,
+                21 => 76,
+                _ => 23,
+            },
+            5 => match state {
+                2 => 45,
+                4 => 50,
+                7 => 54,
+                15 => 62,
+                _ => 24,
+            },
+            6 => match state {
+                5 => 51,
+                16 => 63,
+                _ => 25,
+            },
+            7 => match state {
+                2 | 4..=5 | 7..=16 => 46,
+                6 => 52,
+                18 => 70,
+                _ => 26,
+            },
+            8 => 53,
+            9 => 27,
+            10 => 28,
+            11 => match state {
+                17 => 69,
+                20 => 75,
+                21 => 77,
+                _ => 29,
+            },
+            13 => match state {
+                8 => 55,
+                9 => 56,
+                _ => 30,
+            },
+            14 => 1,
+            15 => match state {
+                12 => 59,
+                13 => 60,
+                14 => 61,
+                _ => 31,
+            },
+            16 => 32,
+            18 => 33,
+            19 => match state {
+                1 => 42,
+                _ => 34,
+            },
+            20 => match state {
+                3 => 47,
+                _ => 35,
+            },
+            21 => match state {
+                3 => 48,
+                _ => 36,
+            },
+            22 => match state {
+                10 => 57,
+                11 => 58,
+                _ => 37,
+            },
+            23 => 71,
+            _ => 0,
+        }
+    }
+    const __TERMINAL: &[&str] = &[
+        r###""(""###,
+        r###"")""###,
+        r###""*""###,
+        r###""+""###,
+        r###"",""###,
+        r###""-""###,
+        r###"".""###,
+        r###"":""###,
+        r###"":=""###,
+        r###"";""###,
+        r###""<""###,
+        r###""=""###,
+        r###"">""###,
+        r###""and""###,
+        r###""begin""###,
+        r###""boolean""###,
+        r###""do""###,
+        r###""else""###,
+        r###""end""###,
+        r###""if""###,
+        r###""integer""###,
+        r###""not""###,
+        r###""or""###,
+        r###""program""###,
+        r###""then""###,
+        r###""var""###,
+        r###""while""###,
+        r###"CHAR"###,
+        r###"ERROR"###,
+        r###"ID"###,
+        r###"NUM"###,
+        r###"REAL"###,
+        r###"STR"###,
+    ];
+    fn __expected_tokens(__state: i8) -> alloc::vec::Vec<alloc::string::String> {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    fn __expected_tokens_from_states<
+        'err,
+    >(
+        __states: &[i8],
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> alloc::vec::Vec<alloc::string::String>
+    {
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            if __accepts(None, __states, Some(index), core::marker::PhantomData::<(&())>) {
+                Some(alloc::string::ToString::to_string(terminal))
+            } else {
+                None
+            }
+        }).collect()
+    }
+    struct __StateMachine<'err>
+    where 
+    {
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __phantom: core::marker::PhantomData<(&'err ())>,
+    }
+    impl<'err> __state_machine::ParserDefinition for __StateMachine<'err>
+    where 
+    {
+        type Location = Position;
+        type Error = lexer::Error;
+        type Token = Token;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<>;
+        type Success = Vec<Spanned<Stmt>>;
+        type StateIndex = i8;
+        type Action = i8;
+        type ReduceIndex = i8;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i8, integer: usize) -> i8 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i8) -> i8 {
+            __action(state, 34 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i8) -> i8 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i8, nt: usize) -> i8 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+        }
+
+        fn expected_tokens(&self, state: i8) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        fn expected_tokens_from_states(&self, states: &[i8]) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens_from_states(states, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            true
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            __Symbol::Variant5(recovery)
+        }
+
+        fn reduce(
+            &mut self,
+            action: i8,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i8>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.errors,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i8) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+        }
+    }
+    fn __token_to_integer<
+        'err,
+    >(
+        __token: &Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Token::LeftParen if true => Some(0),
+            Token::RightParen if true => Some(1),
+            Token::Star if true => Some(2),
+            Token::Plus if true => Some(3),
+            Token::Comma if true => Some(4),
+            Token::Minus if true => Some(5),
+            Token::Dot if true => Some(6),
+            Token::Colon if true => Some(7),
+            Token::Assign if true => Some(8),
+            Token::Semicolon if true => Some(9),
+            Token::LessThan if true => Some(10),
+            Token::Equal if true => Some(11),
+            Token::GreaterThan if true => Some(12),
+            Token::And if true => Some(13),
+            Token::Begin if true => Some(14),
+            Token::Bool if true => Some(15),
+            Token::Do if true => Some(16),
+            Token::Else if true => Some(17),
+            Token::End if true => Some(18),
+            Token::If if true => Some(19),
+            Token::Integer if true => Some(20),
+            Token::Not if true => Some(21),
+            Token::Or if true => Some(22),
+            Token::Program if true => Some(23),
+            Token::Then if true => Some(24),
+            Token::Var if true => Some(25),
+            Token::While if true => Some(26),
+            Token::Char(_) if true => Some(27),
+            Token::Error if true => Some(28),
+            Token::Id(_) if true => Some(29),
+            Token::Number(_) if true => Some(30),
+            Token::Real(_) if true => Some(31),
+            Token::Str(_) if true => Some(32),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'err,
+    >(
+        __token_index: usize,
+        __token: Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __Symbol<>
+    {
+        #[allow(clippy::manual_range_patterns)]match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 28 => __Symbol::Variant0(__token),
+            27 => match __token {
+                Token::Char(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            29 | 32 => match __token {
+                Token::Id(__tok0) | Token::Str(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            30 => match __token {
+                Token::Number(__tok0) if true => __Symbol::Variant3(__tok0),
+                _ => unreachable!(),
+            },
+            31 => match __token {
+                Token::Real(__tok0) if true => __Symbol::Variant4(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    fn __simulate_reduce<
+        'err,
+    >(
+        __reduce_index: i8,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'err>>
+    {
+        match __reduce_index {
+            0 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 0,
+                }
+            }
+            1 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
+            }
+            2 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 2,
+                }
+            }
+            3 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
+            }
+            4 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 2,
+                }
+            }
+            5 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 3,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 3,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 3,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 4,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 6,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 6,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 7,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 8,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 8,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 9,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 9,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 9,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 11,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 11,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 12,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 12,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 13,
+                }
+            }
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 14,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 15,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 16,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 16,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 19,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 21,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 21,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 22,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 24,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 25,
+                }
+            }
+            58 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
+            }
+            59 => __state_machine::SimulatedReduce::Accept,
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct StmtListParser {
+        _priv: (),
+    }
+
+    impl Default for StmtListParser { fn default() -> Self { Self::new() } }
+    impl StmtListParser {
+        pub fn new() -> StmtListParser {
+            StmtListParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'err,
+            __TOKEN: __ToTriple<'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<Spanned<Stmt>>, __lalrpop_util::ParseError<Position, Token, lexer::Error>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'err,
+    >(
+        __error_state: Option<i8>,
+        __states: &[i8],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.extend(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    fn __reduce<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __action: i8,
+        __lookahead_start: Option<&Position>,
+        __states: &mut alloc::vec::Vec<i8>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<Result<Vec<Spanned<Stmt>>,__lalrpop_util::ParseError<Position, Token, lexer::Error>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            57 => {
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            58 => {
+                __reduce58(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            59 => {
+                // __StmtList = StmtList => ActionFn(1);
+                let __sym0 = __pop_Variant7(__symbols);
+                let __start = __sym0.0;
+                let __end = __sym0.2;
+                let __nt = super::__action1::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Expr>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Box<Spanned<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Position, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Program, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<VarDecl>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Spanned<Vec<Spanned<Stmt>>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, String, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Token, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Type, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Spanned<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Spanned<VarDecl>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<String>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<Spanned<VarDecl>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, char, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, f64, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, u32, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __reduce0<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(57);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action57::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 0)
+    }
+    fn __reduce1<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(56);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action56::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 1)
+    }
+    fn __reduce2<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "begin", StmtList, "end" => ActionFn(28);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action28::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 2)
+    }
+    fn __reduce3<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "begin", "end" => ActionFn(29);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action29::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 2)
+    }
+    fn __reduce4<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Block = "begin", Stmts, error, "end" => ActionFn(86);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action86::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (4, 2)
+    }
+    fn __reduce5<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ClosedStmt = SimpleStmt => ActionFn(20);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action20::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 3)
+    }
+    fn __reduce6<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ClosedStmt = "if", Expr, "then", ClosedStmt, "else", ClosedStmt => ActionFn(87);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action87::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (6, 3)
+    }
+    fn __reduce7<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ClosedStmt = "while", Expr, "do", ClosedStmt => ActionFn(88);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action88::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 3)
+    }
+    fn __reduce8<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Decls = "var", VarDecl+ => ActionFn(4);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action4::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (2, 4)
+    }
+    fn __reduce9<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Decls =  => ActionFn(5);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2)).unwrap_or_default();
+        let __end = __start;
+        let __nt = super::__action5::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (0, 4)
+    }
+    fn __reduce10<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr = LogicalExpr => ActionFn(32);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 5)
+    }
+    fn __reduce11<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = "(", Expr, ")" => ActionFn(46);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action46::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 6)
+    }
+    fn __reduce12<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = Id => ActionFn(89);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 6)
+    }
+    fn __reduce13<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = Num => ActionFn(90);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action90::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 6)
+    }
+    fn __reduce14<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Id = ID => ActionFn(53);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action53::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant2(__nt), __end));
+        (1, 7)
+    }
+    fn __reduce15<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IdList = IdList, ",", Id => ActionFn(51);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action51::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 8)
+    }
+    fn __reduce16<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IdList = Id => ActionFn(52);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action52::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (1, 8)
+    }
+    fn __reduce17<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = RelExpr => ActionFn(33);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 9)
+    }
+    fn __reduce18<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = "not", Factor => ActionFn(91);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action91::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (2, 9)
+    }
+    fn __reduce19<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "and", RelExpr => ActionFn(92);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action92::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 9)
+    }
+    fn __reduce20<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalExpr = LogicalExpr, "or", RelExpr => ActionFn(93);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action93::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 9)
+    }
+    fn __reduce21<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Num = NUM => ActionFn(55);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action55::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant3(__nt), __end));
+        (1, 10)
+    }
+    fn __reduce22<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "if", Expr, "then", ClosedStmt => ActionFn(94);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action94::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
+    }
+    fn __reduce23<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "if", Expr, "then", OpenStmt => ActionFn(95);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action95::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
+    }
+    fn __reduce24<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "if", Expr, "then", ClosedStmt, "else", OpenStmt => ActionFn(96);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action96::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (6, 11)
+    }
+    fn __reduce25<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OpenStmt = "while", Expr, "do", OpenStmt => ActionFn(97);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action97::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 11)
+    }
+    fn __reduce26<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Program = "program", SpannedId, ";", Decls, SpannedBlock, "." => ActionFn(2);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action2::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 12)
+    }
+    fn __reduce27<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Program = "program", SpannedId, ";", Decls, SpannedBlock, error => ActionFn(3);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant13(__symbols);
+        let __sym3 = __pop_Variant9(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant14(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action3::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 12)
+    }
+    fn __reduce28<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr => ActionFn(37);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action37::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 13)
+    }
+    fn __reduce29<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, "=", SimpleExpr => ActionFn(98);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action98::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
+    }
+    fn __reduce30<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, "<", SimpleExpr => ActionFn(99);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action99::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
+    }
+    fn __reduce31<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RelExpr = SimpleExpr, ">", SimpleExpr => ActionFn(100);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action100::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 13)
+    }
+    fn __reduce32<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Semis = Stmts, ";" => ActionFn(11);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action11::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce33<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Semis = Semis, ";" => ActionFn(12);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action12::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    fn __reduce34<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Semis = ";" => ActionFn(13);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action13::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 14)
+    }
+    fn __reduce35<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = Term => ActionFn(41);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action41::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 15)
+    }
+    fn __reduce36<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = SimpleExpr, "+", Term => ActionFn(101);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action101::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 15)
+    }
+    fn __reduce37<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleExpr = SimpleExpr, "-", Term => ActionFn(102);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action102::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 15)
+    }
+    fn __reduce38<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = SpannedId, ":=", Expr => ActionFn(103);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action103::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (3, 16)
+    }
+    fn __reduce39<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = "var", IdList, ":", Type => ActionFn(104);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant15(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant11(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action104::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (4, 16)
+    }
+    fn __reduce40<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = Block => ActionFn(105);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce41<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = Expr => ActionFn(106);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action106::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce42<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SimpleStmt = error => ActionFn(107);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action107::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 16)
+    }
+    fn __reduce43<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SpannedBlock = Block => ActionFn(108);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action108::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 17)
+    }
+    fn __reduce44<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SpannedId = Id => ActionFn(109);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (1, 18)
+    }
+    fn __reduce45<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = OpenStmt => ActionFn(14);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action14::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 19)
+    }
+    fn __reduce46<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = ClosedStmt => ActionFn(15);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action15::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (1, 19)
+    }
+    fn __reduce47<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = Stmts => ActionFn(7);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action7::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce48<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // StmtList = Semis => ActionFn(8);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action8::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 20)
+    }
+    fn __reduce49<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmts = Semis, Stmt => ActionFn(9);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action9::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 21)
+    }
+    fn __reduce50<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmts = Stmt => ActionFn(10);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action10::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 21)
+    }
+    fn __reduce51<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Factor => ActionFn(44);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action44::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 22)
+    }
+    fn __reduce52<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Term, "*", Factor => ActionFn(110);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant10(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym2.2;
+        let __nt = super::__action110::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (3, 22)
+    }
+    fn __reduce53<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "integer" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
+    }
+    fn __reduce54<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Type = "boolean" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action50::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 23)
+    }
+    fn __reduce55<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl = IdList, ":", Type, ";" => ActionFn(111);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant15(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym3.2;
+        let __nt = super::__action111::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (4, 24)
+    }
+    fn __reduce56<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl+ = VarDecl => ActionFn(58);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action58::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (1, 25)
+    }
+    fn __reduce57<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // VarDecl+ = VarDecl+, VarDecl => ActionFn(59);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym1.2;
+        let __nt = super::__action59::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (2, 25)
+    }
+    fn __reduce58<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // __Program = Program => ActionFn(0);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym0.2;
+        let __nt = super::__action0::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (1, 26)
+    }
+}
+#[allow(unused_imports)]
+pub use self::__parse__StmtList::StmtListParser;
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action0<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Program, Position),
+) -> Program
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action1<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Vec<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action2<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, name, _): (Position, Spanned<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, decls, _): (Position, Vec<Spanned<VarDecl>>, Position),
+    (_, body, _): (Position, Spanned<Vec<Spanned<Stmt>>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Program
+{
+    Program { name, decls, body }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action3<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, name, _): (Position, Spanned<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, decls, _): (Position, Vec<Spanned<VarDecl>>, Position),
+    (_, body, _): (Position, Spanned<Vec<Spanned<Stmt>>>, Position),
+    (_, e, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+) -> Program
+{
+    {
+        errors.push(e);
+        Program { name, decls, body }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action4<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, __0, _): (Position, alloc::vec::Vec<Spanned<VarDecl>>, Position),
+) -> Vec<Spanned<VarDecl>>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action5<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Vec<Spanned<VarDecl>>
+{
+    Vec::new()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action6<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, names, _): (Position, Vec<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, ty, _): (Position, Type, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Spanned<VarDecl>
+{
+    Spanned::new(VarDecl { names, ty }, l, r)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action7<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action8<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action9<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    {
+        let mut l = l;
+        l.push(*s);
+        l
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action10<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    vec![*s]
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action11<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action12<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action13<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    Vec::new()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action14<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    s
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action15<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    s
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action16<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::If(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action17<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::If(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action18<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, o, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::IfElse(e, s, o), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action19<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::While(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action20<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    s
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action21<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, o, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::IfElse(e, s, o), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action22<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::While(e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action23<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, Spanned<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Assign(i, e), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action24<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, i, _): (Position, Vec<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, t, _): (Position, Type, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::VarDecl(i, t), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action25<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, b, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Block(b), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action26<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    Box::new(Spanned::new(Stmt::Expr(e), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action27<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
+{
+    {
+        errors.push(e);
+        Box::new(Spanned::new(Stmt::Error, l, r))
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action28<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    l
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action29<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+    (_, __1, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    Vec::new()
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action30<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, l, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, el, _): (Position, Position, Position),
+    (_, e, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    (_, er, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    {
+        errors.push(e);
+        let mut l = l;
+        l.push(Spanned::new(Stmt::Error, el, er));
+        l
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action31<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, b, _): (Position, Vec<Spanned<Stmt>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Spanned<Vec<Spanned<Stmt>>>
+{
+    Spanned::new(b, l, r)
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action32<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action33<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action34<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Not(f), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action35<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::And, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action36<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Or, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action37<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    e
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action38<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Equal, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action39<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::LessThan, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action40<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, s, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::GreaterThan, e, s), l, r))
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action41<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    t
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
+fn __action42<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Op(Opcode::Add, e, t), l, r))
+}
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action0<
-    'input,
+fn __action43<
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, Vec<Stmt>, usize),
-) -> Vec<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    __0
+    Box::new(Spanned::new(Expr::Op(Opcode::Sub, e, t), l, r))
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action1<
-    'input,
+fn __action44<
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Vec<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
 {
-    {
-        let mut l = l;
-        l.push(*s);
-        l
-    }
+    f
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action2<
-    'input,
+fn __action45<
+    'err,
 >(
-    input: &'input str,
-    (_, l, _): (usize, Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, t, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, f, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    l
+    Box::new(Spanned::new(Expr::Op(Opcode::Mul, t, f), l, r))
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action3<
-    'input,
+fn __action46<
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Vec<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, _, _): (Position, Token, Position),
+    (_, e, _): (Position, Box<Spanned<Expr>>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Box<Spanned<Expr>>
 {
-    vec![*s]
+    e
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action4<
-    'input,
+fn __action47<
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, String, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    s
+    Box::new(Spanned::new(Expr::Id(i), l, r))
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action5<
-    'input,
+fn __action48<
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, n, _): (Position, u32, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    s
+    Box::new(Spanned::new(Expr::Num(n), l, r))
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action6<
-    'input,
+fn __action49<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+) -> Type
 {
-    Box::new(Stmt::If(e, s))
+    Type::Int
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action7<
-    'input,
+fn __action50<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Token, Position),
+) -> Type
 {
-    Box::new(Stmt::If(e, s))
+    Type::Bool
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action8<
-    'input,
+fn __action51<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Vec<String>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, i, _): (Position, String, Position),
+) -> Vec<String>
 {
-    Box::new(Stmt::IfElse(e, s, r))
+    {
+        let mut l = l;
+        l.push(i);
+        l
+    }
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action9<
-    'input,
+fn __action52<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, i, _): (Position, String, Position),
+) -> Vec<String>
 {
-    Box::new(Stmt::While(e, s))
+    vec![i]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action10<
-    'input,
+fn __action53<
+    'err,
 >(
-    input: &'input str,
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, String, Position),
+) -> String
 {
-    s
+    __0
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action11<
-    'input,
+fn __action54<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, l, _): (Position, Position, Position),
+    (_, i, _): (Position, String, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Spanned<String>
 {
-    Box::new(Stmt::IfElse(e, s, r))
+    Spanned::new(i, l, r)
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action12<
-    'input,
+fn __action55<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, u32, Position),
+) -> u32
 {
-    Box::new(Stmt::While(e, s))
+    __0
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action13<
-    'input,
+fn __action56<
+    'err,
 >(
-    input: &'input str,
-    (_, i, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
 {
-    Box::new(Stmt::Assign(i, e))
+    *__lookbehind
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action14<
-    'input,
+fn __action57<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, i, _): (usize, Vec<String>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, t, _): (usize, Type, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
 {
-    Box::new(Stmt::VarDecl(i, t))
+    *__lookahead
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action15<
-    'input,
+fn __action58<
+    'err,
 >(
-    input: &'input str,
-    (_, b, _): (usize, Vec<Stmt>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, __0, _): (Position, Spanned<VarDecl>, Position),
+) -> alloc::vec::Vec<Spanned<VarDecl>>
 {
-    Box::new(Stmt::Block(b))
+    alloc::vec![__0]
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action16<
-    'input,
+fn __action59<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-) -> Box<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    (_, v, _): (Position, alloc::vec::Vec<Spanned<VarDecl>>, Position),
+    (_, e, _): (Position, Spanned<VarDecl>, Position),
+) -> alloc::vec::Vec<Spanned<VarDecl>>
 {
-    Box::new(Stmt::Expr(e))
+    { let mut v = v; v.push(e); v }
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action17<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action60<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, l, _): (usize, Vec<Stmt>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Vec<Stmt>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Vec<Spanned<Stmt>>, Position),
+    __2: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __3: (Position, Position, Position),
+    __4: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
 {
-    l
+    let __start0 = __1.2;
+    let __end0 = __2.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action30(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action18<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action61<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+    __6: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action19<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action62<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action20<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action63<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, f, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Not(f))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action47(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action21<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action64<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, u32, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Op(Opcode::And, e, r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action22<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action65<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, r, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Op(Opcode::Or, e, r))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action23<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action66<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action35(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action24<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action67<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Op(Opcode::Equal, e, s))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action36(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action25<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action68<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    Box::new(Expr::Op(Opcode::LessThan, e, s))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action26<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action69<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, s, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    Box::new(Expr::Op(Opcode::GreaterThan, e, s))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action27<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action70<
+    'err,
 >(
-    input: &'input str,
-    (_, t, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+    __6: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    t
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action28<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action71<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, t, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    Box::new(Expr::Op(Opcode::Add, e, t))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action29<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action72<
+    'err,
 >(
-    input: &'input str,
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, t, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Op(Opcode::Sub, e, t))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action38(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action30<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action73<
+    'err,
 >(
-    input: &'input str,
-    (_, f, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    f
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action31<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action74<
+    'err,
 >(
-    input: &'input str,
-    (_, t, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, f, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Op(Opcode::Mul, t, f))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action40(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action32<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action75<
+    'err,
 >(
-    input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, e, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    e
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action42(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action33<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action76<
+    'err,
 >(
-    input: &'input str,
-    (_, i, _): (usize, String, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Id(i))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action43(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action34<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action77<
+    'err,
 >(
-    input: &'input str,
-    (_, n, _): (usize, u32, usize),
-) -> Box<Expr>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Spanned<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    Box::new(Expr::Num(n))
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action35<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action78<
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Type
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Vec<String>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Type, Position),
+    __4: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    Type::Integer
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action24(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action36<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action79<
+    'err,
 >(
-    input: &'input str,
-    (_, i, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, l, _): (usize, Vec<String>, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    {
-        let mut l = l;
-        l.push(i);
-        l
-    }
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action25(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action37<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action80<
+    'err,
 >(
-    input: &'input str,
-    (_, i, _): (usize, String, usize),
-) -> Vec<String>
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    vec![i]
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action26(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action38<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action81<
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> String
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __1: (Position, Position, Position),
+) -> Box<Spanned<Stmt>>
 {
-    __0.to_string()
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
 }
 
 #[allow(unused_variables)]
-#[allow(clippy::too_many_arguments, clippy::needless_lifetimes, clippy::just_underscores_and_digits)]
-fn __action39<
-    'input,
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action82<
+    'err,
 >(
-    input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> u32
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+    __1: (Position, Position, Position),
+) -> Spanned<Vec<Spanned<Stmt>>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action31(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action83<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+    __1: (Position, Position, Position),
+) -> Spanned<String>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action54(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action84<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+    __3: (Position, Position, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action45(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action85<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Type, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Position, Position),
+) -> Spanned<VarDecl>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action57(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action6(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action86<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Vec<Spanned<Stmt>>, Position),
+    __2: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+    __3: (Position, Token, Position),
+) -> Vec<Spanned<Stmt>>
+{
+    let __start0 = __2.2;
+    let __end0 = __3.0;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action87<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action61(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action88<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action89<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action63(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action90<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, u32, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action64(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action91<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2;
+    let __end0 = __1.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action92<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action93<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action67(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action94<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action95<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action96<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action97<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Box<Spanned<Expr>>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Box<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action71(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action98<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action99<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action100<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action74(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action101<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action102<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action103<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Spanned<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action77(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action104<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Vec<String>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Type, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action105<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action79(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action106<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action107<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, __lalrpop_util::ErrorRecovery<Position, Token, lexer::Error>, Position),
+) -> Box<Spanned<Stmt>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action108<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<Spanned<Stmt>>, Position),
+) -> Spanned<Vec<Spanned<Stmt>>>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action82(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action109<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, String, Position),
+) -> Spanned<String>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action83(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action110<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Box<Spanned<Expr>>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Box<Spanned<Expr>>, Position),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2;
+    let __end0 = __2.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action84(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments, clippy::needless_lifetimes,
+    clippy::just_underscores_and_digits)]
+fn __action111<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, lexer::Error>>,
+    __0: (Position, Vec<String>, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Type, Position),
+    __3: (Position, Token, Position),
+) -> Spanned<VarDecl>
 {
-    __0.parse().unwrap()
+    let __start0 = __3.2;
+    let __end0 = __3.2;
+    let __temp0 = __action56(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action85(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 #[allow(clippy::type_complexity, dead_code)]
 
-pub  trait __ToTriple<'input, >
+pub  trait __ToTriple<'err, >
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>>;
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>>;
 }
 
-impl<'input, > __ToTriple<'input, > for (usize, Token<'input>, usize)
+impl<'err, > __ToTriple<'err, > for (Position, Token, Position)
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, Token<'input>, usize), &'static str>
+impl<'err, > __ToTriple<'err, > for Result<(Position, Token, Position), lexer::Error>
 {
-    fn to_triple(value: Self) -> Result<(usize,Token<'input>,usize), __lalrpop_util::ParseError<usize, Token<'input>, &'static str>> {
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, lexer::Error>> {
         match value {
             Ok(v) => Ok(v),
             Err(error) => Err(__lalrpop_util::ParseError::User { error }),
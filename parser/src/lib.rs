@@ -1,17 +1,631 @@
-pub mod ast;
-
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[allow(clippy::all)]
+pub mod c_grammar;
 #[rustfmt::skip]
 #[allow(dead_code)]
 #[allow(clippy::all)]
 pub mod grammar;
 
+use std::{io::BufRead, ops::Range};
+
+pub use ast::{self, Dialect};
+use lalrpop_util::{ErrorRecovery, ParseError};
+use lexer::{Lexer, LexerConfig, SpannedLexer};
 pub use lexer::{Position, Token};
 use thiserror::Error;
 // use lalrpop_util::lalrpop_mod;
 
 // lalrpop_mod!(pub grammar);
 
+use ast::{Program, Spanned, Stmt};
+use grammar::{ProgramParser, StmtListParser};
+
+/// A syntax or lexical error.
+///
+/// Syntax errors carry the span of the offending input and the terminals the
+/// parser expected there, as spelled in the grammar, e.g. `"begin"` or `ID`.
 #[derive(Debug, Error)]
-pub enum Error {}
+pub enum Error {
+    #[error("unexpected token {token:?} at {}{}", .span.start, expected_list(.expected))]
+    UnexpectedToken {
+        token: Token,
+        span: Range<Position>,
+        expected: Vec<String>,
+    },
+
+    #[error("unexpected end of input at {}{}", .span.start, expected_list(.expected))]
+    UnexpectedEof {
+        span: Range<Position>,
+        expected: Vec<String>,
+    },
+
+    #[error("invalid token at {}{}", .span.start, expected_list(.expected))]
+    InvalidToken {
+        span: Range<Position>,
+        expected: Vec<String>,
+    },
+
+    /// A token after a complete input. Only the end of input was expected,
+    /// so `expected` is empty.
+    #[error("extra token {token:?} at {}", .span.start)]
+    ExtraToken {
+        token: Token,
+        span: Range<Position>,
+        expected: Vec<String>,
+    },
+
+    #[error(transparent)]
+    Lexer(#[from] lexer::Error),
+}
 
 pub type Result<T> = std::result::Result<T, Error>;
+
+fn expected_list(expected: &[String]) -> String {
+    match expected {
+        [] => String::new(),
+        [x] => format!(", expected {x}"),
+        _ => format!(", expected one of {}", expected.join(", ")),
+    }
+}
+
+impl Error {
+    /// Returns the span of a syntax error, or `None` for a lexical error.
+    pub fn span(&self) -> Option<Range<Position>> {
+        match self {
+            Error::UnexpectedToken { span, .. }
+            | Error::UnexpectedEof { span, .. }
+            | Error::InvalidToken { span, .. }
+            | Error::ExtraToken { span, .. } => Some(span.clone()),
+            Error::Lexer(_) => None,
+        }
+    }
+
+    /// Returns the terminals expected where a syntax error occurred.
+    pub fn expected(&self) -> &[String] {
+        match self {
+            Error::UnexpectedToken { expected, .. }
+            | Error::UnexpectedEof { expected, .. }
+            | Error::InvalidToken { expected, .. }
+            | Error::ExtraToken { expected, .. } => expected,
+            Error::Lexer(_) => &[],
+        }
+    }
+
+    /// Returns where the error starts, for sorting errors in source order.
+    fn start(&self) -> Option<Position> {
+        match self {
+            Error::Lexer(
+                lexer::Error::InvalidChar(_, start)
+                | lexer::Error::InvalidByte(_, start)
+                | lexer::Error::UnterminatedComment(start),
+            ) => Some(*start),
+            Error::Lexer(
+                lexer::Error::NumberOverflow { span, .. }
+                | lexer::Error::MalformedNumber { span, .. }
+                | lexer::Error::UnterminatedString { span }
+                | lexer::Error::InvalidCharLiteral { span }
+                | lexer::Error::InvalidEscape { span, .. },
+            ) => Some(span.start),
+            Error::Lexer(_) => None,
+            _ => self.span().map(|x| x.start),
+        }
+    }
+}
+
+impl From<ParseError<Position, Token, lexer::Error>> for Error {
+    fn from(value: ParseError<Position, Token, lexer::Error>) -> Self {
+        match value {
+            ParseError::InvalidToken { location } => Error::InvalidToken {
+                span: location..location,
+                expected: Vec::new(),
+            },
+            ParseError::UnrecognizedEof { location, expected } => Error::UnexpectedEof {
+                span: location..location,
+                expected,
+            },
+            ParseError::UnrecognizedToken {
+                token: (start, token, end),
+                expected,
+            } => Error::UnexpectedToken {
+                token,
+                span: start..end,
+                expected,
+            },
+            ParseError::ExtraToken {
+                token: (start, token, end),
+            } => Error::ExtraToken {
+                token,
+                span: start..end,
+                expected: Vec::new(),
+            },
+            ParseError::User { error } => Error::Lexer(error),
+        }
+    }
+}
+
+/// The outcome of parsing with error recovery.
+///
+/// Syntax errors at statement boundaries are recovered from by replacing the
+/// offending statement with [`Stmt::Error`], so `ast` is still available when
+/// `errors` is not empty. It is `None` only if the parser could not recover.
+#[derive(Debug)]
+pub struct Parsed<T> {
+    pub ast: Option<T>,
+    /// All lexical and syntax errors, in source order.
+    pub errors: Vec<Error>,
+}
+
+impl<T> Parsed<T> {
+    /// Maps the AST, keeping the errors.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Parsed<U> {
+        Parsed {
+            ast: self.ast.map(f),
+            errors: self.errors,
+        }
+    }
+
+    /// Returns the AST if there were no errors at all.
+    pub fn into_result(self) -> std::result::Result<T, Vec<Error>> {
+        match self.ast {
+            Some(ast) if self.errors.is_empty() => Ok(ast),
+            _ => Err(self.errors),
+        }
+    }
+}
+
+/// Parses a list of statements in the Pascal-like dialect from `src`,
+/// failing with all errors if there are any.
+///
+/// This is [`parse_stmt_list`] without the partial AST. Complete programs
+/// with a `program` header are parsed by [`parse_program`].
+pub fn parse_stmts(src: &str) -> std::result::Result<Vec<Spanned<Stmt>>, Vec<Error>> {
+    parse_stmt_list(src, Dialect::Pascal).into_result()
+}
+
+/// Parses a complete program, starting with a `program` header and ending
+/// with `.`, from `src`. Only the Pascal-like dialect has programs.
+pub fn parse_program(src: &str) -> Parsed<Program> {
+    parse_with(
+        src,
+        Dialect::Pascal,
+        (Position::default(), 0),
+        |errors, tokens| ProgramParser::new().parse(errors, tokens),
+    )
+}
+
+/// Parses a list of statements in `dialect` from `src`. The statements are
+/// separated by `;` in the Pascal-like dialect and terminated by `;` in the
+/// C-like one.
+pub fn parse_stmt_list(src: &str, dialect: Dialect) -> Parsed<Vec<Spanned<Stmt>>> {
+    parse_stmt_list_at(src, dialect, (Position::default(), 0))
+}
+
+/// Parses a list of statements like [`parse_stmt_list`], for a `src` that is
+/// a part of a larger file starting at `start`, a position and byte offset in
+/// that file. Spans and errors then point into the file.
+pub fn parse_stmt_list_at(
+    src: &str,
+    dialect: Dialect,
+    start: (Position, usize),
+) -> Parsed<Vec<Spanned<Stmt>>> {
+    parse_with(src, dialect, start, |errors, tokens| match dialect {
+        Dialect::Pascal => StmtListParser::new().parse(errors, tokens),
+        Dialect::C => c_grammar::StmtListParser::new().parse(errors, tokens),
+    })
+}
+
+/// Returns whether `src` starts with a `program` header and should be parsed
+/// with [`parse_program`]. Only the Pascal-like dialect has programs.
+pub fn is_program(src: &str, dialect: Dialect) -> bool {
+    dialect == Dialect::Pascal
+        && Tokens::new(src.as_bytes())
+            .ok()
+            .and_then(|mut x| x.next())
+            .is_some_and(|x| matches!(x, Ok((_, Token::Program, _))))
+}
+
+/// Returns whether `src` contains a comment. Comments are not part of the AST,
+/// so printing a parsed `src` would drop them.
+pub fn has_comments(src: &str, dialect: Dialect) -> bool {
+    Lexer::new(src.as_bytes()).is_ok_and(|lexer| {
+        lexer
+            .with_config(lexer_config(dialect).keep_comments(true))
+            .with_recovery()
+            .any(|x| matches!(x, Ok(Token::Comment(_))))
+    })
+}
+
+/// Returns the lexer configuration for `dialect`.
+pub fn lexer_config(dialect: Dialect) -> LexerConfig {
+    match dialect {
+        Dialect::Pascal => LexerConfig::default(),
+        Dialect::C => LexerConfig::c_like(),
+    }
+}
+
+type Recovered = Vec<ErrorRecovery<Position, Token, lexer::Error>>;
+
+/// Runs `parse` over the tokens of `src` with lexical error recovery enabled,
+/// collecting lexical and recovered syntax errors.
+fn parse_with<T>(
+    src: &str,
+    dialect: Dialect,
+    (position, offset): (Position, usize),
+    parse: impl FnOnce(
+        &mut Recovered,
+        &mut Tokens<&[u8]>,
+    ) -> std::result::Result<T, ParseError<Position, Token, lexer::Error>>,
+) -> Parsed<T> {
+    let mut tokens = match Lexer::new(src.as_bytes()) {
+        Ok(lexer) => Tokens::from(
+            lexer
+                .with_config(lexer_config(dialect))
+                .with_recovery()
+                .starting_at(position, offset),
+        ),
+        Err(e) => {
+            return Parsed {
+                ast: None,
+                errors: vec![e.into()],
+            }
+        }
+    };
+    let mut recovered = Vec::new();
+    let result = parse(&mut recovered, &mut tokens);
+
+    let mut errors: Vec<Error> = tokens
+        .inner
+        .into_inner()
+        .into_errors()
+        .into_iter()
+        .map(Error::from)
+        .collect();
+    let syntax = recovered.into_iter().map(|x| x.error);
+    let (ast, fatal) = match result {
+        Ok(ast) => (Some(ast), None),
+        Err(e) => (None, Some(e)),
+    };
+    // `Token::Error` stands for a lexical error, which is already reported
+    errors.extend(syntax.chain(fatal).map(Error::from).filter(|e| {
+        !matches!(
+            e,
+            Error::UnexpectedToken {
+                token: Token::Error,
+                ..
+            }
+        )
+    }));
+    errors.sort_by_key(Error::start);
+    Parsed { ast, errors }
+}
+
+/// Adapts a [`Lexer`] to the `(Position, Token, Position)` triples consumed by
+/// the LALRPOP parsers in [`grammar`].
+#[derive(Debug)]
+pub struct Tokens<R> {
+    inner: SpannedLexer<R>,
+}
+
+impl<R: BufRead> Tokens<R> {
+    pub fn new(reader: R) -> lexer::Result<Self> {
+        Ok(Lexer::new(reader)?.into())
+    }
+}
+
+impl<R: BufRead> From<Lexer<R>> for Tokens<R> {
+    fn from(value: Lexer<R>) -> Self {
+        Self {
+            inner: value.spanned(),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Tokens<R> {
+    type Item = lexer::Result<(Position, Token, Position)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|x| x.map(|x| (x.start, x.token, x.end)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ast::{Expr, Opcode, Span};
+
+    #[test]
+    fn parse_from_lexer() {
+        let input = "var a, b: integer; a := b * 2 // comment\n; while not a do a := 0";
+        let mut errors = Vec::new();
+        let ast = StmtListParser::new()
+            .parse(&mut errors, Tokens::new(input.as_bytes()).unwrap())
+            .unwrap();
+
+        assert_eq!(ast.len(), 3);
+        assert!(matches!(ast[2].node, Stmt::While(_, _)));
+    }
+
+    #[test]
+    fn spans() {
+        let ast = parse_stmt_list("a := b * 2 + 1;\nwhile a do\n  a := a - 1", Dialect::Pascal)
+            .into_result()
+            .unwrap();
+
+        assert_eq!(
+            ast[0].span,
+            Span::new(Position::new(1, 0), Position::new(1, 14))
+        );
+        let Stmt::Assign(target, expr) = &ast[0].node else {
+            panic!()
+        };
+        assert_eq!(
+            target.span,
+            Span::new(Position::new(1, 0), Position::new(1, 1))
+        );
+        assert_eq!(
+            expr.span,
+            Span::new(Position::new(1, 5), Position::new(1, 14))
+        );
+        let Expr::Op(Opcode::Add, lhs, _) = &expr.node else {
+            panic!()
+        };
+        assert_eq!(
+            lhs.span,
+            Span::new(Position::new(1, 5), Position::new(1, 10))
+        );
+
+        assert_eq!(
+            ast[1].span,
+            Span::new(Position::new(2, 0), Position::new(3, 12))
+        );
+    }
+
+    #[test]
+    fn program() {
+        let program = parse_program(include_str!("../../lexer/tests/00_program.txt"))
+            .into_result()
+            .unwrap();
+
+        assert_eq!(program.name.node, "example");
+        assert_eq!(program.decls.len(), 1);
+        assert_eq!(program.decls[0].node.names.len(), 3);
+        assert_eq!(program.body.node.len(), 4);
+        assert_eq!(program.body.span.start, Position::new(3, 4));
+        assert_eq!(program.body.span.end, Position::new(9, 7));
+
+        let program = parse_program("program p; begin a := 1 end.")
+            .into_result()
+            .unwrap();
+        assert!(program.decls.is_empty());
+        assert!(parse_program("a := 1").into_result().is_err());
+
+        let parsed = parse_program("program p; begin a := 1 end");
+        assert!(parsed.ast.is_some());
+        assert!(matches!(&parsed.errors[..], [Error::UnexpectedEof { .. }]));
+
+        let parsed = parse_program("program p; begin a := 1 end;");
+        assert!(parsed.ast.is_some());
+        match &parsed.errors[..] {
+            [Error::UnexpectedToken {
+                token: Token::Semicolon,
+                expected,
+                ..
+            }] => assert_eq!(expected, &[r#"".""#]),
+            _ => panic!("{:?}", parsed.errors),
+        }
+
+        assert_eq!(parse_stmts("a := 1; b := 2").unwrap().len(), 2);
+        assert!(parse_stmts("a := ").is_err());
+    }
+
+    #[test]
+    fn lexer_error_position() {
+        let errors = parse_stmt_list("a := 1;\nb := ?", Dialect::Pascal)
+            .into_result()
+            .unwrap_err();
+
+        match &errors[..] {
+            [Error::Lexer(lexer::Error::InvalidChar('?', position))] => {
+                assert_eq!(*position, Position::new(2, 5))
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let errors = parse_stmt_list("a := 1;\nif a then := 2", Dialect::Pascal)
+            .into_result()
+            .unwrap_err();
+        match &errors[..] {
+            [Error::UnexpectedToken {
+                token: Token::Assign,
+                span,
+                expected,
+            }] => {
+                assert_eq!(*span, Position::new(2, 10)..Position::new(2, 12));
+                assert!(expected.contains(&"ID".to_string()));
+                assert!(expected.contains(&r#""begin""#.to_string()));
+            }
+            _ => panic!(),
+        }
+
+        let errors = parse_stmt_list("a := (1 +", Dialect::Pascal)
+            .into_result()
+            .unwrap_err();
+        match &errors[..] {
+            [e @ Error::UnexpectedEof { span, expected }] => {
+                assert_eq!(*span, Position::new(1, 9)..Position::new(1, 9));
+                assert!(expected.contains(&"NUM".to_string()));
+                assert!(e
+                    .to_string()
+                    .starts_with("unexpected end of input at 1:9, expected one of"));
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn recovery() {
+        let input = "a := 1;\nb := := 2;\nbegin c := ; d := 3 end;\ne := ?;\nf := 4";
+        let parsed = parse_stmt_list(input, Dialect::Pascal);
+        let ast = parsed.ast.unwrap();
+
+        assert_eq!(ast.len(), 5);
+        assert!(matches!(ast[1].node, Stmt::Error));
+        let Stmt::Block(block) = &ast[2].node else {
+            panic!()
+        };
+        assert!(matches!(block[0].node, Stmt::Error));
+        assert!(matches!(block[1].node, Stmt::Assign(_, _)));
+        assert!(matches!(ast[4].node, Stmt::Assign(_, _)));
+
+        match &parsed.errors[..] {
+            [Error::UnexpectedToken {
+                token: Token::Assign,
+                span: first,
+                ..
+            }, Error::UnexpectedToken {
+                token: Token::Semicolon,
+                span: second,
+                ..
+            }, Error::Lexer(lexer::Error::InvalidChar('?', third))] => {
+                assert_eq!(first.start, Position::new(2, 5));
+                assert_eq!(second.start, Position::new(3, 11));
+                assert_eq!(*third, Position::new(4, 5));
+            }
+            _ => panic!("{:?}", parsed.errors),
+        }
+    }
+
+    #[test]
+    fn block_recovery() {
+        // a missing `;` skips to the closing `end`, keeping the statements before it
+        let parsed = parse_stmt_list("begin a := 1; b := 2 c d end; e := 3", Dialect::Pascal);
+        let ast = parsed.ast.unwrap();
+        assert_eq!(ast.len(), 2);
+        let Stmt::Block(block) = &ast[0].node else {
+            panic!()
+        };
+        assert_eq!(block.len(), 3);
+        assert!(matches!(block[1].node, Stmt::Assign(_, _)));
+        assert!(matches!(block[2].node, Stmt::Error));
+        assert_eq!(block[2].span.start, Position::new(1, 21));
+        assert!(matches!(
+            &parsed.errors[..],
+            [Error::UnexpectedToken { token: Token::Id(id), .. }] if id == "c"
+        ));
+
+        let parsed = parse_stmt_list("while a do { a := 1; b := 2 c }; d := 3;", Dialect::C);
+        let ast = parsed.ast.unwrap();
+        assert_eq!(ast.len(), 2);
+        let Stmt::While(_, body) = &ast[0].node else {
+            panic!()
+        };
+        let Stmt::Block(block) = &body.node else {
+            panic!()
+        };
+        assert!(matches!(block[0].node, Stmt::Assign(_, _)));
+        assert!(matches!(block[1].node, Stmt::Error));
+        assert_eq!(parsed.errors.len(), 1);
+
+        // an unclosed block is reported at the end of the input
+        let parsed = parse_stmt_list("a := 1; begin b := 2; c := 3", Dialect::Pascal);
+        let ast = parsed.ast.unwrap();
+        assert!(matches!(ast[0].node, Stmt::Assign(_, _)));
+        assert!(matches!(ast[1].node, Stmt::Error));
+        assert!(matches!(&parsed.errors[..], [Error::UnexpectedEof { .. }]));
+    }
+
+    #[test]
+    fn c_dialect() {
+        let input = "int a; bool begin;\nif a > 1 then { a := 2; } else { Begin := := 3; };";
+        let parsed = parse_stmt_list(input, Dialect::C);
+        let ast = parsed.ast.unwrap();
+
+        assert_eq!(ast.len(), 3);
+        assert!(
+            matches!(&ast[1].node, Stmt::VarDecl(names, ast::Type::Bool) if names == &["begin"])
+        );
+        let Stmt::IfElse(_, _, o) = &ast[2].node else {
+            panic!()
+        };
+        let Stmt::Block(block) = &o.node else {
+            panic!()
+        };
+        assert!(matches!(block[0].node, Stmt::Error));
+        assert_eq!(o.span.start, Position::new(2, 31));
+
+        match &parsed.errors[..] {
+            [Error::UnexpectedToken {
+                token: Token::Assign,
+                span,
+                ..
+            }] => assert_eq!(span.start, Position::new(2, 42)),
+            _ => panic!("{:?}", parsed.errors),
+        }
+        assert!(parse_stmt_list("begin a := 1 end", Dialect::C)
+            .into_result()
+            .is_err());
+        assert!(
+            parse_stmt_list("var int: integer; int := 1", Dialect::Pascal)
+                .into_result()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn comments() {
+        assert!(has_comments("a := 1 // one\n", Dialect::Pascal));
+        assert!(has_comments("(* one *) a := 1", Dialect::Pascal));
+        assert!(has_comments("a := 1; // one", Dialect::C));
+        assert!(!has_comments("a := 1; b := '//'", Dialect::Pascal));
+        assert!(!has_comments("a := 1", Dialect::C));
+    }
+
+    #[test]
+    fn parse_at() {
+        let parsed =
+            parse_stmt_list_at("a := 1; b := ", Dialect::Pascal, (Position::new(3, 0), 20));
+        assert_eq!(parsed.ast.unwrap()[0].span.start, Position::new(3, 0));
+        match &parsed.errors[..] {
+            [Error::UnexpectedEof { span, .. }] => assert_eq!(span.start, Position::new(3, 12)),
+            _ => panic!("{:?}", parsed.errors),
+        }
+        let parsed = parse_stmt_list_at("a := ？", Dialect::Pascal, (Position::new(2, 0), 5));
+        assert!(matches!(
+            parsed.errors[..],
+            [Error::Lexer(lexer::Error::InvalidChar('？', start)), ..] if start == Position::new(2, 5)
+        ));
+    }
+
+    #[test]
+    fn empty_statements() {
+        let ast = parse_stmts("a;; b;").unwrap();
+        assert_eq!(ast.len(), 2);
+        assert_eq!(ast[1].span.start, Position::new(1, 4));
+        assert!(parse_stmts(";").unwrap().is_empty());
+
+        let ast = parse_stmts("begin ; end; begin ;; a end").unwrap();
+        assert!(matches!(&ast[0].node, Stmt::Block(b) if b.is_empty()));
+        assert!(matches!(&ast[1].node, Stmt::Block(b) if b.len() == 1));
+    }
+
+    #[test]
+    fn declaration_order() {
+        let names = ["a", "b", "c"];
+        let ast = parse_stmts("var a, b, c: integer").unwrap();
+        assert!(matches!(&ast[0].node, Stmt::VarDecl(x, _) if x == &names));
+        let ast = parse_stmt_list("int a, b, c;", Dialect::C)
+            .into_result()
+            .unwrap();
+        assert!(matches!(&ast[0].node, Stmt::VarDecl(x, _) if x == &names));
+        let program = parse_program("program p; var a, b, c: integer; begin end.")
+            .into_result()
+            .unwrap();
+        assert_eq!(program.decls[0].node.names, names);
+    }
+}
@@ -1,10 +1,21 @@
-use std::{env, fs::read_to_string};
+use std::{
+    env,
+    fs::{read_to_string, write},
+    process::exit,
+};
 
-use lalrpop_util::ParseError;
-use parser::{ast::Stmt, grammar::StmtListParser};
+use parser::{
+    ast::{
+        dump::{dump_program, dump_stmt_list, Format},
+        printer::{print_program, print_stmt_list},
+        Spanned, Stmt,
+    },
+    has_comments, is_program, parse_program, parse_stmt_list, parse_stmt_list_at, Dialect, Error,
+    Position,
+};
 
-fn print_stmt(stmt: &Stmt, buf: &mut String) {
-    match stmt {
+fn print_stmt(stmt: &Spanned<Stmt>, buf: &mut String) {
+    match &stmt.node {
         Stmt::If(_, s) => {
             buf.push_str(" if-then 分支语句，嵌套");
             print_stmt(s, buf);
@@ -25,40 +36,196 @@ fn print_stmt(stmt: &Stmt, buf: &mut String) {
         Stmt::VarDecl(_, _) => {
             buf.push_str("变量声明语句");
         }
-        Stmt::Block(_) => {
-            buf.push_str("语句块");
+        Stmt::Block(ss) => {
+            buf.push_str("语句块，依次嵌套");
+            for (i, s) in ss.iter().enumerate() {
+                if i > 0 {
+                    buf.push('；');
+                }
+                print_stmt(s, buf);
+            }
         }
         Stmt::Expr(_) => {
             buf.push_str("表达式");
         }
+        Stmt::Error => {
+            buf.push_str("错误语句");
+        }
+    }
+}
+
+fn describe(stmt: &Spanned<Stmt>) -> String {
+    let mut buf = String::new();
+    print_stmt(stmt, &mut buf);
+    buf.trim_start().to_string()
+}
+
+fn report(errors: Vec<Error>) {
+    for e in errors {
+        match e {
+            Error::Lexer(e) => println!("词法错误：{e}"),
+            e => println!("语法错误：{e}"),
+        }
+    }
+}
+
+/// Formats each of `paths` in place, or with `--check` only reports the files
+/// that are not formatted.
+fn fmt(mut args: impl Iterator<Item = String>) {
+    let usage = || -> ! {
+        println!("用法：parser fmt [--check] [--dialect pascal|c] <文件>...");
+        exit(2);
+    };
+    let mut check = false;
+    let mut dialect = Dialect::default();
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--check" => check = true,
+            "--dialect" => {
+                dialect = args
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        usage();
+    }
+
+    let mut failed = false;
+    for path in paths {
+        let file_contents = read_to_string(&path).unwrap();
+        let formatted = if is_program(&file_contents, dialect) {
+            parse_program(&file_contents)
+                .into_result()
+                .map(|x| print_program(&x, dialect))
+        } else {
+            parse_stmt_list(&file_contents, dialect)
+                .into_result()
+                .map(|x| print_stmt_list(&x, dialect))
+        };
+        match formatted {
+            Ok(formatted) if formatted == file_contents => {}
+            // the AST holds no comments, so formatting would delete them
+            Ok(_) if has_comments(&file_contents, dialect) => {
+                println!("{path}：包含注释，格式化会删除注释，未格式化");
+                failed = true;
+            }
+            Ok(_) if check => {
+                println!("{path}：格式不正确");
+                failed = true;
+            }
+            Ok(formatted) => write(&path, formatted).unwrap(),
+            Err(errors) => {
+                println!("{path}：");
+                report(errors);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        exit(1);
     }
 }
 
 fn main() {
-    let mut args = env::args();
-    let path = args.nth(1).unwrap();
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().is_some_and(|x| x == "fmt") {
+        args.next();
+        fmt(args);
+        return;
+    }
+
+    let usage = || -> ! {
+        println!(
+            "用法：parser [--per-line] [--dialect pascal|c] [--emit ast=json|sexp|dot|tree] <文件>\n      parser fmt [--check] [--dialect pascal|c] <文件>..."
+        );
+        exit(2);
+    };
+    let mut per_line = false;
+    let mut dialect = Dialect::default();
+    let mut emit = None;
+    let mut path = None;
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--per-line" => per_line = true,
+            "--dialect" => {
+                dialect = args
+                    .next()
+                    .and_then(|x| x.parse().ok())
+                    .unwrap_or_else(|| usage())
+            }
+            "--emit" => {
+                let format = args
+                    .next()
+                    .and_then(|x| x.strip_prefix("ast=")?.parse::<Format>().ok());
+                emit = Some(format.unwrap_or_else(|| usage()));
+            }
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else { usage() };
     let file_contents = read_to_string(path).unwrap();
-    let parser = StmtListParser::new();
 
-    for line in file_contents.lines() {
-        let mut buf = String::new();
-        match parser.parse(line) {
-            Ok(ast) => {
-                for stmt in ast {
-                    print_stmt(&stmt, &mut buf);
-                    println!("{}", buf.trim_start());
+    // the original exercise, where each line is a separate statement list
+    if per_line {
+        let mut offset = 0;
+        for (i, line) in file_contents.split_inclusive('\n').enumerate() {
+            let start = (Position::new(i as u32 + 1, 0), offset);
+            offset += line.len();
+            let parsed = parse_stmt_list_at(line, dialect, start);
+            match (&parsed.ast, emit) {
+                (Some(ast), Some(format)) => print!("{}", dump_stmt_list(ast, format)),
+                (Some(ast), None) => {
+                    for stmt in ast {
+                        println!("{}", describe(stmt));
+                    }
                 }
+                (None, _) => {}
             }
-            Err(e) => {
-                let location = match e {
-                    ParseError::InvalidToken { location } => location,
-                    ParseError::UnrecognizedEof { location, .. } => location,
-                    ParseError::UnrecognizedToken { token, .. } => token.0,
-                    ParseError::ExtraToken { token } => token.0,
-                    ParseError::User { .. } => todo!(),
-                };
-                println!("语法错误，位置 {location}");
-            }
+            report(parsed.errors);
+        }
+        return;
+    }
+
+    if let Some(format) = emit {
+        let (dump, errors) = if is_program(&file_contents, dialect) {
+            let parsed = parse_program(&file_contents);
+            (parsed.ast.map(|x| dump_program(&x, format)), parsed.errors)
+        } else {
+            let parsed = parse_stmt_list(&file_contents, dialect);
+            (
+                parsed.ast.map(|x| dump_stmt_list(&x, format)),
+                parsed.errors,
+            )
+        };
+        print!("{}", dump.unwrap_or_default());
+        if !errors.is_empty() {
+            report(errors);
+            exit(1);
         }
+        return;
+    }
+
+    let parsed = if is_program(&file_contents, dialect) {
+        parse_program(&file_contents).map(|program| {
+            println!("程序 {}", program.name.node);
+            for decl in &program.decls {
+                println!("第 {} 行：变量声明", decl.span.start.line);
+            }
+            program.body.node
+        })
+    } else {
+        parse_stmt_list(&file_contents, dialect)
+    };
+    for stmt in parsed.ast.iter().flatten() {
+        println!("第 {} 行：{}", stmt.span.start.line, describe(stmt));
+    }
+    if !parsed.errors.is_empty() {
+        report(parsed.errors);
+        exit(1);
     }
 }
@@ -0,0 +1,70 @@
+use parser::{
+    ast::dump::{dump_stmt_list, Format},
+    parse_stmt_list, Dialect,
+};
+
+fn dump(src: &str, format: Format) -> String {
+    dump_stmt_list(
+        &parse_stmt_list(src, Dialect::Pascal).into_result().unwrap(),
+        format,
+    )
+}
+
+#[test]
+fn sexp() {
+    assert_eq!(
+        dump(
+            "a := b - c * (d + 1); if not a then x := 1 else y := 2",
+            Format::Sexp
+        ),
+        "(block (:= a (- b (* c (+ d 1)))) (if (not a) (:= x 1) (:= y 2)))\n"
+    );
+    let long = "var alpha, beta, gamma: integer; while alpha < beta do begin alpha := alpha + 1; gamma := alpha end";
+    assert_eq!(
+        dump(long, Format::Sexp),
+        "(block
+  (var alpha beta gamma int)
+  (while (< alpha beta) (block (:= alpha (+ alpha 1)) (:= gamma alpha))))\n"
+    );
+}
+
+#[test]
+fn tree() {
+    assert_eq!(
+        dump("a := 1 + b;\nc", Format::Tree),
+        "block
+├── := [1:0-1:10]
+│   ├── a [1:0-1:1]
+│   └── + [1:5-1:10]
+│       ├── 1 [1:5-1:6]
+│       └── b [1:9-1:10]
+└── c [2:0-2:1]
+"
+    );
+}
+
+#[test]
+fn dot() {
+    assert_eq!(
+        dump("a := 1", Format::Dot),
+        "digraph ast {
+    node [shape=box];
+    n0 [label=\"block\"];
+    n1 [label=\":=\"];
+    n2 [label=\"a\"];
+    n1 -> n2;
+    n3 [label=\"1\", shape=ellipse];
+    n1 -> n3;
+    n0 -> n1;
+}
+"
+    );
+}
+
+#[test]
+fn json() {
+    let json: serde_json::Value = serde_json::from_str(&dump("a := 1", Format::Json)).unwrap();
+    assert_eq!(json[0]["node"]["Assign"][0]["node"], "a");
+    assert_eq!(json[0]["node"]["Assign"][1]["node"]["Num"], 1);
+    assert_eq!(json[0]["span"]["end"]["column"], 6);
+}
@@ -0,0 +1,204 @@
+use parser::{
+    ast::{
+        printer::{print_program, print_stmt, print_stmt_list},
+        Stmt,
+    },
+    is_program, parse_program, parse_stmt_list, Dialect,
+};
+
+fn round_trip(src: &str) -> String {
+    let ast = parse_stmt_list(src, Dialect::Pascal).into_result().unwrap();
+    let printed = print_stmt_list(&ast, Dialect::Pascal);
+    assert_eq!(
+        parse_stmt_list(&printed, Dialect::Pascal)
+            .into_result()
+            .unwrap(),
+        ast
+    );
+    printed
+}
+
+#[test]
+fn program() {
+    let src = include_str!("../../lexer/tests/00_program.txt");
+    let parsed = parse_program(src);
+    assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+    let ast = parsed.ast.unwrap();
+    let printed = print_program(&ast, Dialect::Pascal);
+    assert_eq!(
+        printed,
+        "program example;\nvar\n    k, m, n: integer;\nbegin\n    k := 8;\n    m := 5;\n    n := k + m;\n    if n > 10 then\n        k := k - 1\nend.\n"
+    );
+    assert_eq!(parse_program(&printed).into_result().unwrap(), ast);
+}
+
+#[test]
+fn parentheses() {
+    assert_eq!(
+        round_trip("a := (b - (c - d)) * ((e)) + f * (g + h)"),
+        "a := (b - (c - d)) * e + f * (g + h)\n"
+    );
+    assert_eq!(
+        round_trip("not (a = b) and (not c or d) or (e and f)"),
+        "not (a = b) and (not c or d) or (e and f)\n"
+    );
+    assert_eq!(
+        round_trip("(a < b) = c; a > (b = c)"),
+        "(a < b) = c;\na > (b = c)\n"
+    );
+}
+
+#[test]
+fn statements() {
+    assert_eq!(
+        round_trip(
+            "var a, b: integer; while a do begin if a then b := 1 else if b then a := 2 else begin a := 0; b := 0 end end"
+        ),
+        "var a, b: integer;\nwhile a do begin\n    if a then\n        b := 1\n    else if b then\n        a := 2\n    else begin\n        a := 0;\n        b := 0\n    end\nend\n"
+    );
+    round_trip("if a then begin if b then c end else while a do if b then c else d");
+}
+
+#[test]
+fn empty_blocks() {
+    assert_eq!(
+        round_trip("while a do begin end; if a then begin end else begin end; begin end"),
+        "while a do begin\nend;\nif a then begin\nend else begin\nend;\nbegin\nend\n"
+    );
+
+    let ast = parse_stmt_list("while a do { }; { };", Dialect::C)
+        .into_result()
+        .unwrap();
+    let printed = print_stmt_list(&ast, Dialect::C);
+    assert_eq!(printed, "while a do {\n};\n{\n};\n");
+    assert_eq!(
+        parse_stmt_list(&printed, Dialect::C).into_result().unwrap(),
+        ast
+    );
+
+    let program = parse_program("program p; begin end.")
+        .into_result()
+        .unwrap();
+    let printed = print_program(&program, Dialect::Pascal);
+    assert_eq!(printed, "program p;\nbegin\nend.\n");
+    assert_eq!(parse_program(&printed).into_result().unwrap(), program);
+}
+
+#[test]
+fn dangling_else() {
+    let ast = parse_stmt_list("if a then if b then c", Dialect::Pascal)
+        .into_result()
+        .unwrap();
+    let Stmt::If(e, s) = ast[0].node.clone() else {
+        panic!()
+    };
+    let stmt = Stmt::IfElse(e, s, Box::new(ast[0].clone()));
+    assert_eq!(
+        print_stmt(&stmt, Dialect::Pascal),
+        "if a then begin\n    if b then\n        c\nend else if a then\n    if b then\n        c"
+    );
+}
+
+const C_PROGRAM: &str = "int a, b; bool flag;
+a := 3;
+flag := not (a > 10) and (a > 1);
+while a > 0 do {
+    if not flag then { b := b * (a - 1); } else { b := b + 1; };
+    { a := a - 1; };
+};
+";
+
+#[test]
+fn c_dialect() {
+    let ast = parse_stmt_list(C_PROGRAM, Dialect::C)
+        .into_result()
+        .unwrap();
+    let printed = print_stmt_list(&ast, Dialect::C);
+    assert_eq!(
+        printed,
+        "int a, b;
+bool flag;
+a := 3;
+flag := not (a > 10) and a > 1;
+while a > 0 do {
+    if not flag then {
+        b := b * (a - 1);
+    } else {
+        b := b + 1;
+    };
+    {
+        a := a - 1;
+    };
+};
+"
+    );
+    assert_eq!(
+        parse_stmt_list(&printed, Dialect::C).into_result().unwrap(),
+        ast
+    );
+    assert_eq!(
+        print_stmt_list(
+            &parse_stmt_list(&printed, Dialect::C).into_result().unwrap(),
+            Dialect::C
+        ),
+        printed
+    );
+}
+
+#[test]
+fn cross_dialect() {
+    let ast = parse_stmt_list(C_PROGRAM, Dialect::C)
+        .into_result()
+        .unwrap();
+    let pascal = print_stmt_list(&ast, Dialect::Pascal);
+    assert!(pascal.starts_with("var a, b: integer;\nvar flag: boolean;\n"));
+    let reparsed = parse_stmt_list(&pascal, Dialect::Pascal)
+        .into_result()
+        .unwrap();
+    assert_eq!(reparsed, ast);
+    assert_eq!(
+        print_stmt_list(&reparsed, Dialect::C),
+        print_stmt_list(&ast, Dialect::C)
+    );
+}
+
+/// The dialect each lexer sample is written in.
+const SAMPLES: [(&str, Dialect); 3] = [
+    ("00_program.txt", Dialect::Pascal),
+    ("01_program.txt", Dialect::Pascal),
+    ("02_program.txt", Dialect::C),
+];
+
+/// Every lexer sample parses without errors and round-trips.
+#[test]
+fn fixtures() {
+    let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/../lexer/tests");
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = path.file_name().unwrap().to_str().unwrap();
+        if !name.ends_with("_program.txt") {
+            continue;
+        }
+        let Some(&(_, dialect)) = SAMPLES.iter().find(|x| x.0 == name) else {
+            panic!("no dialect for sample {name}")
+        };
+        let src = std::fs::read_to_string(&path).unwrap();
+        if is_program(&src, dialect) {
+            let ast = parse_program(&src).into_result().unwrap();
+            let printed = print_program(&ast, dialect);
+            assert_eq!(
+                parse_program(&printed).into_result().unwrap(),
+                ast,
+                "{name}"
+            );
+        } else {
+            let ast = parse_stmt_list(&src, dialect).into_result().unwrap();
+            let printed = print_stmt_list(&ast, dialect);
+            assert_eq!(
+                parse_stmt_list(&printed, dialect).into_result().unwrap(),
+                ast,
+                "{name}"
+            );
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use parser::{
+    ast::{
+        printer::print_stmt_list,
+        visit::{fold_expr, walk_expr, walk_expr_mut, Fold, Visitor, VisitorMut},
+        Expr, Opcode, Spanned, Stmt,
+    },
+    parse_stmt_list, Dialect,
+};
+
+fn parse(src: &str) -> Vec<Spanned<Stmt>> {
+    parse_stmt_list(src, Dialect::Pascal).into_result().unwrap()
+}
+
+#[test]
+fn visitor() {
+    struct Ids(Vec<String>);
+
+    impl Visitor for Ids {
+        fn visit_expr(&mut self, expr: &Spanned<Expr>) {
+            if let Expr::Id(id) = &expr.node {
+                self.0.push(id.clone());
+            }
+            walk_expr(self, expr);
+        }
+    }
+
+    let mut ids = Ids(Vec::new());
+    for stmt in &parse("a := b + c; while d do begin if not e then f else g * 2 end") {
+        ids.visit_stmt(stmt);
+    }
+    assert_eq!(ids.0, ["b", "c", "d", "e", "f", "g"]);
+}
+
+#[test]
+fn visitor_mut() {
+    struct Rename;
+
+    impl VisitorMut for Rename {
+        fn visit_expr_mut(&mut self, expr: &mut Spanned<Expr>) {
+            if let Expr::Id(id) = &mut expr.node {
+                id.make_ascii_uppercase();
+            }
+            walk_expr_mut(self, expr);
+        }
+    }
+
+    let mut ast = parse("a := b; if c then begin d end");
+    for stmt in &mut ast {
+        Rename.visit_stmt_mut(stmt);
+    }
+    assert_eq!(
+        print_stmt_list(&ast, Dialect::Pascal),
+        "a := B;\nif C then begin\n    D\nend\n"
+    );
+}
+
+#[test]
+fn fold() {
+    struct ConstantFolding;
+
+    impl Fold for ConstantFolding {
+        fn fold_expr(&mut self, expr: Spanned<Expr>) -> Spanned<Expr> {
+            let expr = fold_expr(self, expr);
+            let Expr::Op(op, lhs, rhs) = &expr.node else {
+                return expr;
+            };
+            let (Expr::Num(x), Expr::Num(y)) = (&lhs.node, &rhs.node) else {
+                return expr;
+            };
+            let value = match op {
+                Opcode::Add => x.checked_add(*y),
+                Opcode::Sub => x.checked_sub(*y),
+                Opcode::Mul => x.checked_mul(*y),
+                _ => None,
+            };
+            match value {
+                Some(value) => Spanned {
+                    node: Expr::Num(value),
+                    ..expr
+                },
+                None => expr,
+            }
+        }
+    }
+
+    let ast: Vec<_> = parse("a := 2 * (3 + 4) - b; if 1 - 2 then c := 2 * 3")
+        .into_iter()
+        .map(|x| ConstantFolding.fold_stmt(x))
+        .collect();
+    assert_eq!(
+        print_stmt_list(&ast, Dialect::Pascal),
+        "a := 14 - b;\nif 1 - 2 then\n    c := 6\n"
+    );
+}
+
+#[test]
+fn c_dialect() {
+    struct Ids(Vec<String>);
+
+    impl Visitor for Ids {
+        fn visit_expr(&mut self, expr: &Spanned<Expr>) {
+            if let Expr::Id(id) = &expr.node {
+                self.0.push(id.clone());
+            }
+            walk_expr(self, expr);
+        }
+    }
+
+    struct Negate;
+
+    impl Fold for Negate {
+        fn fold_expr(&mut self, expr: Spanned<Expr>) -> Spanned<Expr> {
+            let expr = fold_expr(self, expr);
+            match expr.node {
+                Expr::Not(e) => *e,
+                node => Spanned { node, ..expr },
+            }
+        }
+    }
+
+    let ast = parse_stmt_list(
+        "if not a then { b := c; } else { while d do { e; }; };",
+        Dialect::C,
+    )
+    .ast
+    .unwrap();
+    let mut ids = Ids(Vec::new());
+    ids.visit_stmt(&ast[0]);
+    assert_eq!(ids.0, ["a", "c", "d", "e"]);
+
+    let ast: Vec<_> = ast.into_iter().map(|x| Negate.fold_stmt(x)).collect();
+    assert_eq!(
+        print_stmt_list(&ast, Dialect::C),
+        "if a then {\n    b := c;\n} else {\n    while d do {\n        e;\n    };\n};\n"
+    );
+}